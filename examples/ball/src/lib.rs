@@ -241,7 +241,7 @@ pub async fn start_ball() -> Result<(), String> {
         if vga.draw_frame() {
             return Ok(()); // quit
         }
-        sleep(14).await; // target 70 fps
+        sleep(vga.frame_time().as_millis() as u32).await; // refresh rate of the mode
     }
 }
 
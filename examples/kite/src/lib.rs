@@ -3,7 +3,7 @@ pub mod web;
 
 //Kite example from https://github.com/jagregory/abrash-black-book/blob/master/src/chapter-49.md (LISTING 49.5)
 use vga::{
-    SCReg, VGABuilder, set_vertical_display_end,
+    CRTReg, SCReg, VGABuilder, set_vertical_display_end,
     util::{
         copy_screen_to_screen_x, copy_system_to_screen_masked_x, fill_pattern_x, fill_rectangle_x,
         sleep,
@@ -42,6 +42,10 @@ pub async fn start_kite() -> Result<(), String> {
     //enable Mode X
     let mem_mode = vga.get_sc_data(SCReg::MemoryMode);
     vga.set_sc_data(SCReg::MemoryMode, (mem_mode & !0x08) | 0x04); //turn off chain 4 & odd/even
+    let underline = vga.get_crt_data(CRTReg::UnderlineLocation);
+    vga.set_crt_data(CRTReg::UnderlineLocation, underline & !0x40); //turn off doubleword
+    let crt_mode = vga.get_crt_data(CRTReg::CRTCModeControl);
+    vga.set_crt_data(CRTReg::CRTCModeControl, crt_mode | 0x40); //turn on byte mode
     set_vertical_display_end(&mut vga, 480);
 
     draw_background(&mut vga, BG_START_OFFSET);
@@ -78,7 +82,7 @@ pub async fn start_kite() -> Result<(), String> {
         if vga.draw_frame() {
             return Ok(()); // quit
         }
-        sleep(14).await; // target 70 fps
+        sleep(vga.frame_time().as_millis() as u32).await; // refresh rate of the mode
     }
 }
 
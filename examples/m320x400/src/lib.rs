@@ -58,7 +58,7 @@ pub async fn start_m320x400() -> Result<(), String> {
     vga.set_crt_data(CRTReg::UnderlineLocation, underline & !0x40); //turn off doubleword
 
     let crt_mode = vga.get_crt_data(CRTReg::CRTCModeControl);
-    vga.set_crt_data(CRTReg::CRTCModeControl, crt_mode & 0x40); //turn on byte mode bit
+    vga.set_crt_data(CRTReg::CRTCModeControl, crt_mode | 0x40); //turn on byte mode bit
 
     let line_list = vec![
         new_line(130, 110, 1, 0, 60, 0),
@@ -93,7 +93,7 @@ pub async fn start_m320x400() -> Result<(), String> {
                     return Ok(()); // quit
                 }
 
-                sleep(14).await; // target 70 fps
+                sleep(vga.frame_time().as_millis() as u32).await; // refresh rate of the mode
             }
         }
     }
@@ -5,7 +5,7 @@ use std::env;
 use std::fs;
 
 use vga::util::{self, sleep};
-use vga::{ColorReg, SCReg, VGABuilder};
+use vga::{CRTReg, ColorReg, SCReg, VGABuilder};
 
 const SCREEN_WIDTH: usize = 320;
 const SCREEN_HEIGHT: usize = 200;
@@ -22,6 +22,10 @@ pub async fn start_palette() -> Result<(), String> {
     //enable Mode X
     let mem_mode = vga.get_sc_data(SCReg::MemoryMode);
     vga.set_sc_data(SCReg::MemoryMode, (mem_mode & !0x08) | 0x04); //turn off chain 4 & odd/even
+    let underline = vga.get_crt_data(CRTReg::UnderlineLocation);
+    vga.set_crt_data(CRTReg::UnderlineLocation, underline & !0x40); //turn off doubleword
+    let crt_mode = vga.get_crt_data(CRTReg::CRTCModeControl);
+    vga.set_crt_data(CRTReg::CRTCModeControl, crt_mode | 0x40); //turn on byte mode
 
     let mut args = env::args();
     if args.len() == 2 {
@@ -55,7 +59,7 @@ pub async fn start_palette() -> Result<(), String> {
         if vga.draw_frame() {
             return Ok(()); // quit
         }
-        sleep(14).await; // target 70 fps
+        sleep(vga.frame_time().as_millis() as u32).await; // refresh rate of the mode
     }
 }
 
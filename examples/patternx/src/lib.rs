@@ -2,7 +2,7 @@
 pub mod web;
 
 //Rectangle example from https://github.com/jagregory/abrash-black-book/blob/master/src/chapter-48.md (LISTING 48.2)
-use vga::{SCReg, set_vertical_display_end};
+use vga::{CRTReg, SCReg, set_vertical_display_end};
 use vga::{
     VGABuilder,
     util::{fill_pattern_x, sleep},
@@ -45,6 +45,10 @@ pub async fn start_patternx() -> Result<(), String> {
     //enable Mode X
     let mem_mode = vga.get_sc_data(SCReg::MemoryMode);
     vga.set_sc_data(SCReg::MemoryMode, (mem_mode & !0x08) | 0x04); //turn off chain 4 & odd/even
+    let underline = vga.get_crt_data(CRTReg::UnderlineLocation);
+    vga.set_crt_data(CRTReg::UnderlineLocation, underline & !0x40); //turn off doubleword
+    let crt_mode = vga.get_crt_data(CRTReg::CRTCModeControl);
+    vga.set_crt_data(CRTReg::CRTCModeControl, crt_mode | 0x40); //turn on byte mode
     set_vertical_display_end(&mut vga, 480);
 
     for j in 0..4 {
@@ -65,6 +69,6 @@ pub async fn start_patternx() -> Result<(), String> {
         if vga.draw_frame() {
             return Ok(()); // quit
         }
-        sleep(14).await; // target 70 fps
+        sleep(vga.frame_time().as_millis() as u32).await; // refresh rate of the mode
     }
 }
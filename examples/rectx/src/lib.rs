@@ -2,7 +2,7 @@
 pub mod web;
 
 //Example from https://www.phatcode.net/res/224/files/html/ch47/47-07.html (LISTING 47.6)
-use vga::{SCReg, set_vertical_display_end};
+use vga::{CRTReg, SCReg, set_vertical_display_end};
 use vga::{
     VGABuilder,
     util::{fill_rectangle_x, sleep},
@@ -18,6 +18,10 @@ pub async fn start_rectx() -> Result<(), String> {
     //enable Mode X
     let mem_mode = vga.get_sc_data(SCReg::MemoryMode);
     vga.set_sc_data(SCReg::MemoryMode, (mem_mode & !0x08) | 0x04); //turn off chain 4 & odd/even
+    let underline = vga.get_crt_data(CRTReg::UnderlineLocation);
+    vga.set_crt_data(CRTReg::UnderlineLocation, underline & !0x40); //turn off doubleword
+    let crt_mode = vga.get_crt_data(CRTReg::CRTCModeControl);
+    vga.set_crt_data(CRTReg::CRTCModeControl, crt_mode | 0x40); //turn on byte mode
     set_vertical_display_end(&mut vga, 480);
 
     fill_rectangle_x(&mut vga, 0, 0, 320, 240, 0, 0);
@@ -1,8 +1,12 @@
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
+use crate::raster::RegWrite;
 /// Contains common functionality shared across all backend implementations
-use crate::{AttributeReg, CRTReg, VGAEmu};
+use crate::util::{
+    get_character_maps_regs, get_line_compare_regs, get_overscan_regs, get_width_regs,
+};
+use crate::{ADDRESS_MASK, AttributeReg, CRTReg, GCReg, SCReg, VGAEmu};
 
 // A stripped down input version for the backend controls
 pub struct EmuInput {
@@ -24,7 +28,7 @@ impl EmuInput {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct RGB {
     r: u8,
     g: u8,
@@ -40,87 +44,482 @@ pub trait PixelBuffer {
     fn set_rgb(&mut self, offset: usize, r: u8, g: u8, b: u8);
 }
 
+/// Renders the current frame with the renderer matching the register state
+/// (text, 256 color or planar). With overscan enabled the active display area is
+/// surrounded by the border in the overscan color.
+/// h = number of lines to render, pitch = length of one row in bytes
+#[cfg_attr(feature = "tracing", instrument(skip_all))]
+pub fn render<T: PixelBuffer + ?Sized>(vga: &mut VGAEmu, h: usize, buffer: &mut T, pitch: usize) {
+    vga.poll_vertical_interrupt();
+    if !vga.overscan {
+        render_active(vga, h, buffer, pitch);
+        return;
+    }
+
+    let border = get_overscan_regs(&vga.regs);
+    let (left, top) = (border.left as usize, border.top as usize);
+    let w_active = get_width_regs(&vga.regs) as usize;
+    let h_active = h.saturating_sub(top + border.bottom as usize);
+    let w = pitch / T::PIXEL_WIDTH;
+
+    let overscan_ix = vga.regs.get_attribute_reg(AttributeReg::OverscanColor);
+    let color = dac_color(vga.get_palette_256()[(overscan_ix & vga.regs.get_pel_mask()) as usize]);
+    for y in 0..h {
+        let active_line = y >= top && y < top + h_active;
+        for x in 0..w {
+            if active_line && x >= left && x < left + w_active {
+                continue;
+            }
+            buffer.set_rgb(y * pitch + x * T::PIXEL_WIDTH, color.r, color.g, color.b);
+        }
+    }
+
+    let mut active = OffsetBuffer {
+        buffer,
+        base: top * pitch + left * T::PIXEL_WIDTH,
+    };
+    render_active(vga, h_active, &mut active, pitch);
+}
+
+fn render_active<T: PixelBuffer + ?Sized>(
+    vga: &mut VGAEmu, h: usize, buffer: &mut T, pitch: usize,
+) {
+    vga.latch_display();
+    match vga.take_raster_frame() {
+        Some(frame) => {
+            //render from the state at the start of the frame, then restore the current state
+            let regs = std::mem::replace(&mut vga.regs, frame.regs);
+            let palette = std::mem::replace(&mut vga.palette_256, frame.palette);
+            render_lines(vga, h, buffer, pitch, &frame.writes);
+            vga.regs = regs;
+            vga.palette_256 = palette;
+        }
+        None => render_lines(vga, h, buffer, pitch, &[]),
+    }
+}
+
+/// Renders the frame scan line by scan line. The writes (scan line, write) are
+/// applied before the scan line they occurred in is rendered.
+fn render_lines<T: PixelBuffer + ?Sized>(
+    vga: &mut VGAEmu, h: usize, buffer: &mut T, pitch: usize, writes: &[(usize, RegWrite)],
+) {
+    //an offset of 0 repeats the first row on every scan line
+    let offset_delta = vga.regs.get_crt_data(CRTReg::Offset) as usize;
+    let mem_offset = vga.mem_offset();
+    let mut counter = ScanCounter::new(vga, mem_offset + byte_panning(vga), offset_delta * 2);
+
+    let mut writes = writes.iter().peekable();
+    for y in 0..h {
+        let mut changed = false;
+        while let Some((_, write)) = writes.next_if(|(line, _)| *line <= y) {
+            write.apply(&mut vga.regs, &mut vga.palette_256);
+            changed = true;
+        }
+        if changed {
+            counter.update(vga);
+        }
+
+        if is_text(vga) {
+            render_text_line(vga, &counter, y, buffer, pitch);
+        } else if is_linear(vga) {
+            render_linear_line(vga, &counter, y, buffer, pitch);
+        } else {
+            render_planar_line(vga, &counter, y, buffer, pitch);
+        }
+        counter.next_line(y);
+    }
+}
+
+/// Moves the origin of a buffer (the active display area inside the border)
+struct OffsetBuffer<'a, T: PixelBuffer + ?Sized> {
+    buffer: &'a mut T,
+    base: usize,
+}
+
+impl<T: PixelBuffer + ?Sized> PixelBuffer for OffsetBuffer<'_, T> {
+    const PIXEL_WIDTH: usize = T::PIXEL_WIDTH;
+    fn set_rgb(&mut self, offset: usize, r: u8, g: u8, b: u8) {
+        self.buffer.set_rgb(self.base + offset, r, g, b);
+    }
+}
+
 /// pitch = length of one row in bytes
 #[cfg_attr(feature = "tracing", instrument(skip_all))]
 pub fn render_planar<T: PixelBuffer + ?Sized>(
-    vga: &mut VGAEmu, mem_offset_p: usize, offset_delta: usize, h: usize, buffer: &mut T,
-    pitch: usize,
+    vga: &VGAEmu, mem_offset_p: usize, offset_delta: usize, h: usize, buffer: &mut T, pitch: usize,
 ) {
-    let mut x: usize = 0;
-    let mut y: usize = 0;
-    let mut mem_offset = mem_offset_p;
-    let max_scan = (vga.regs.get_crt_data(CRTReg::MaximumScanLine) & 0x1F) as usize + 1;
+    let mut counter = ScanCounter::new(vga, mem_offset_p + byte_panning(vga), offset_delta * 2);
+    for y in 0..h {
+        render_planar_line(vga, &counter, y, buffer, pitch);
+        counter.next_line(y);
+    }
+}
+
+fn render_planar_line<T: PixelBuffer + ?Sized>(
+    vga: &VGAEmu, counter: &ScanCounter, y: usize, buffer: &mut T, pitch: usize,
+) {
+    let crtc = CrtcAddress::new(vga);
     let w_bytes = vga.regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as usize + 2; //+1 for exclusive intervall, +1 for "overshot" with potential hpan
+    //dot clock / 2: every pixel is output twice
+    let dot_repeat = if vga.regs.get_sc_data(SCReg::ClockingMode) & 0x08 != 0 {
+        2
+    } else {
+        1
+    };
+    //shift register interleave: 2 bits per pixel (CGA 4 color modes)
+    let interleave = vga.regs.get_gc_data(GCReg::GraphicsMode) & 0x20 != 0;
+    let colors = attribute_colors(vga);
+
+    //in 8 dot modes only the values 0-7 shift, 8-15 wrap around
+    let hpan = counter.hpan(vga) & 0x07;
+    let mut x: usize = 0;
+    for mem_byte in 0..w_bytes {
+        let addr = crtc.address(
+            counter.mem_offset + mem_byte / crtc.count_by,
+            counter.row_scan,
+        );
+        //odd/even writes keep the odd bytes at the odd offsets of plane 1/3
+        let odd = if crtc.word_mode() { addr | 0x01 } else { addr };
+        let v0 = vga.mem[0][addr];
+        let v1 = vga.mem[1][odd];
+        let v2 = vga.mem[2][addr];
+        let v3 = vga.mem[3][odd];
+
+        let start = if mem_byte == 0 { hpan } else { 0 };
+        let end = if mem_byte == w_bytes - 1 { hpan } else { 8 };
+        for b in start..end {
+            let pixel = if interleave {
+                //4 pixels from the even planes, then 4 pixels from the odd planes
+                let (low, high) = if b < 4 { (v0, v2) } else { (v1, v3) };
+                let shift = 6 - (b & 0x03) * 2;
+                ((low >> shift) & 0x03) | (((high >> shift) & 0x03) << 2)
+            } else {
+                let bx = (1 << (7 - b)) as u8;
+                bit_x(v0, bx, 0) | bit_x(v1, bx, 1) | bit_x(v2, bx, 2) | bit_x(v3, bx, 3)
+            };
+
+            let color = colors[pixel as usize];
+            for _ in 0..dot_repeat {
+                let offset = y * pitch + x * T::PIXEL_WIDTH;
+                buffer.set_rgb(offset, color.r, color.g, color.b);
+                x += 1;
+            }
+        }
+    }
+}
 
-    for _ in 0..(h / max_scan) {
-        for _ in 0..max_scan {
-            let hpan = vga
-                .regs
+/// Follows the CRTC address and row scan counter through the scan lines of a frame
+struct ScanCounter {
+    mem_offset: usize,
+    row_delta: usize,
+    row_scan: usize,
+    row_scans: usize,
+    double: usize,
+    scan_double: usize,
+    line_compare: usize,
+    split: bool,
+    pel_compat: bool,
+}
+
+impl ScanCounter {
+    fn new(vga: &VGAEmu, mem_offset: usize, row_delta: usize) -> ScanCounter {
+        let mut counter = ScanCounter {
+            mem_offset,
+            row_delta,
+            //the first character row starts at the preset row scan (bits 0-4)
+            row_scan: (vga.regs.get_crt_data(CRTReg::PresetRowScan) & 0x1F) as usize,
+            row_scans: 1,
+            double: 0,
+            scan_double: 1,
+            line_compare: 0,
+            split: false,
+            pel_compat: false,
+        };
+        counter.read_regs(vga);
+        counter
+    }
+
+    /// Takes over register changes in the middle of the frame
+    fn update(&mut self, vga: &VGAEmu) {
+        self.row_delta = vga.regs.get_crt_data(CRTReg::Offset) as usize * 2;
+        self.read_regs(vga);
+    }
+
+    fn read_regs(&mut self, vga: &VGAEmu) {
+        let msl = vga.regs.get_crt_data(CRTReg::MaximumScanLine);
+        self.row_scans = (msl & 0x1F) as usize + 1;
+        //with scan doubling (bit 7) every scan line is output twice
+        self.scan_double = if msl & 0x80 != 0 { 2 } else { 1 };
+        self.double = self.double.min(self.scan_double - 1);
+        self.line_compare = get_line_compare_regs(&vga.regs) as usize;
+        self.pel_compat = vga.regs.get_attribute_reg(AttributeReg::ModeControl) & 0x20 != 0;
+    }
+
+    /// Horizontal pixel panning for the current line. With the PEL panning
+    /// compatibility bit set the split screen part is not panned.
+    fn hpan(&self, vga: &VGAEmu) -> u8 {
+        if self.split && self.pel_compat {
+            0
+        } else if vga.raster.is_some() {
+            //raster effects replay the panning writes per scan line
+            vga.regs
                 .get_attribute_reg(AttributeReg::HorizontalPixelPanning)
-                & 0xF;
-            for mem_byte in 0..w_bytes {
-                let v0 = vga.raw_read_mem(0, mem_offset + mem_byte);
-                let v1 = vga.raw_read_mem(1, mem_offset + mem_byte);
-                let v2 = vga.raw_read_mem(2, mem_offset + mem_byte);
-                let v3 = vga.raw_read_mem(3, mem_offset + mem_byte);
-
-                let start = if mem_byte == 0 { hpan } else { 0 };
-                let end = if mem_byte == w_bytes - 1 { hpan } else { 8 };
-                for b in start..end {
-                    let bx = (1 << (7 - b)) as u8;
-                    let mut pixel = bit_x(v0, bx, 0);
-                    pixel |= bit_x(v1, bx, 1);
-                    pixel |= bit_x(v2, bx, 2);
-                    pixel |= bit_x(v3, bx, 3);
-
-                    let color = default_16_color(pixel);
-                    let offset = y * pitch + x * T::PIXEL_WIDTH;
-                    buffer.set_rgb(offset, color.r, color.g, color.b);
-
-                    x += 1;
-                }
+        } else {
+            vga.pel_panning()
+        }
+    }
+
+    /// Advances the counters after the scan line `y`. At the line compare scan line
+    /// the address and row scan counter are reset to 0 (split screen).
+    fn next_line(&mut self, y: usize) {
+        if y == self.line_compare {
+            self.mem_offset = 0;
+            self.row_scan = 0;
+            self.double = 0;
+            self.split = true;
+            return;
+        }
+        self.double += 1;
+        if self.double == self.scan_double {
+            self.double = 0;
+            self.row_scan += 1;
+            if self.row_scan >= self.row_scans {
+                self.row_scan = 0;
+                self.mem_offset += self.row_delta;
             }
-            x = 0;
-            y += 1;
         }
-        mem_offset += offset_delta * 2;
     }
 }
 
+/// Byte panning (CRTReg::PresetRowScan bits 5-6): the start address is
+/// moved by up to 3 character clocks
+fn byte_panning(vga: &VGAEmu) -> usize {
+    ((vga.regs.get_crt_data(CRTReg::PresetRowScan) >> 5) & 0x03) as usize
+}
+
+/// The CRTC address generation: translates the memory address counter into the
+/// address the display memory is scanned from
+struct CrtcAddress {
+    crtc_mode: u8,
+    /// 0 = byte mode, 1 = word mode, 2 = doubleword mode
+    shift: usize,
+    /// the address counter is only advanced every `count_by` character clocks
+    count_by: usize,
+}
+
+impl CrtcAddress {
+    fn new(vga: &VGAEmu) -> CrtcAddress {
+        let crtc_mode = vga.regs.get_crt_data(CRTReg::CRTCModeControl);
+        let underline_location = vga.regs.get_crt_data(CRTReg::UnderlineLocation);
+        //doubleword mode (UnderlineLocation bit 6) overrides the byte/word mode select
+        let shift = if underline_location & 0x40 != 0 {
+            2
+        } else if crtc_mode & 0x40 == 0 {
+            1
+        } else {
+            0
+        };
+        let count_by = if underline_location & 0x20 != 0 {
+            4
+        } else if crtc_mode & 0x08 != 0 {
+            2
+        } else {
+            1
+        };
+        CrtcAddress {
+            crtc_mode,
+            shift,
+            count_by,
+        }
+    }
+
+    fn word_mode(&self) -> bool {
+        self.shift == 1
+    }
+
+    /// The (16 bit) display memory address for the address counter `ma` on the row scan line `row_scan`
+    fn address(&self, ma: usize, row_scan: usize) -> usize {
+        let addr = match self.shift {
+            //MA 12/13 are rotated into the address bits 0/1
+            2 => (ma << 2) | ((ma >> 12) & 0x03),
+            //MA 13 or MA 15 (address wrap, CRTCModeControl bit 5) is rotated into address bit 0
+            1 => {
+                let wrap_bit = if self.crtc_mode & 0x20 != 0 { 15 } else { 13 };
+                (ma << 1) | ((ma >> wrap_bit) & 0x01)
+            }
+            _ => ma,
+        };
+        row_scan_address(addr & ADDRESS_MASK, row_scan, self.crtc_mode)
+    }
+}
+
+/// CGA compatible addressing: unless disabled in CRTReg::CRTCModeControl (bits 0/1)
+/// the row scan counter bits 0/1 replace the address bits 13/14
+fn row_scan_address(addr: usize, row_scan: usize, crtc_mode: u8) -> usize {
+    let mut addr = addr;
+    if crtc_mode & 0x01 == 0 {
+        addr = (addr & !0x2000) | ((row_scan & 0x01) << 13);
+    }
+    if crtc_mode & 0x02 == 0 {
+        addr = (addr & !0x4000) | ((row_scan & 0x02) << 13);
+    }
+    addr
+}
+
+/// Renders the 256 color modes (chained mode 13h and the unchained "Mode X" variants).
+/// Every character clock outputs the 4 pixels stored at the same address in plane 0-3
+/// (in mode 13h the doubleword address of the chain 4 plane offset).
+/// pitch = length of one row in bytes
 #[cfg_attr(feature = "tracing", instrument(skip_all))]
 pub fn render_linear<T: PixelBuffer + ?Sized>(
-    vga: &VGAEmu, mem_offset_p: usize, offset_delta: usize, h: usize, v_stretch: usize,
-    buffer: &mut T,
+    vga: &VGAEmu, mem_offset_p: usize, offset_delta: usize, h: usize, buffer: &mut T, pitch: usize,
+) {
+    let mut counter = ScanCounter::new(vga, mem_offset_p + byte_panning(vga), offset_delta * 2);
+    for y in 0..h {
+        render_linear_line(vga, &counter, y, buffer, pitch);
+        counter.next_line(y);
+    }
+}
+
+fn render_linear_line<T: PixelBuffer + ?Sized>(
+    vga: &VGAEmu, counter: &ScanCounter, y: usize, buffer: &mut T, pitch: usize,
 ) {
-    let mut mem_offset = mem_offset_p;
-    let max_scan = (vga.regs.get_crt_data(CRTReg::MaximumScanLine) & 0x1F) as usize + 1;
+    let crtc = CrtcAddress::new(vga);
     let w_bytes = vga.regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as usize + 1;
+    let w_pixels = w_bytes * 4;
+    //in 8 bit color mode two dots form one pixel, the dot clock / 2 doubles this again
+    let mut pixel_dots = if vga.regs.get_attribute_reg(AttributeReg::ModeControl) & 0x40 != 0 {
+        2
+    } else {
+        1
+    };
+    if vga.regs.get_sc_data(SCReg::ClockingMode) & 0x08 != 0 {
+        pixel_dots *= 2;
+    }
 
     let palette_lock = vga.get_palette_256();
+    let pel_mask = vga.regs.get_pel_mask();
+
+    //the panning is in half pixel steps in 8 bit color mode
+    let pan = ((counter.hpan(vga) & 0x07) >> 1) as usize;
+    let mut buffer_offset = y * pitch;
+    for x_byte in 0..=w_bytes {
+        let addr = crtc.address(
+            counter.mem_offset + x_byte / crtc.count_by,
+            counter.row_scan,
+        );
+        for p in 0..4 {
+            let ix = x_byte * 4 + p;
+            if ix < pan || ix >= w_pixels + pan {
+                continue;
+            }
+            let v = vga.mem[p][addr];
+            let color = dac_color(palette_lock[(v & pel_mask) as usize]);
+            for _ in 0..pixel_dots {
+                buffer.set_rgb(buffer_offset, color.r, color.g, color.b);
+                buffer_offset += T::PIXEL_WIDTH;
+            }
+        }
+    }
+}
+
+/// Renders the alphanumeric modes. The character codes are read from plane 0,
+/// the attributes from plane 1 (odd/even) and the glyphs from the character
+/// maps in plane 2.
+#[cfg_attr(feature = "tracing", instrument(skip_all))]
+pub fn render_text<T: PixelBuffer + ?Sized>(
+    vga: &VGAEmu, mem_offset_p: usize, offset_delta: usize, h: usize, buffer: &mut T, pitch: usize,
+) {
+    let mut counter = ScanCounter::new(vga, mem_offset_p + byte_panning(vga), offset_delta * 2);
+    for y in 0..h {
+        render_text_line(vga, &counter, y, buffer, pitch);
+        counter.next_line(y);
+    }
+}
+
+fn render_text_line<T: PixelBuffer + ?Sized>(
+    vga: &VGAEmu, counter: &ScanCounter, y: usize, buffer: &mut T, pitch: usize,
+) {
+    let crtc = CrtcAddress::new(vga);
+    let columns = vga.regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as usize + 1;
+    let underline_location = (vga.regs.get_crt_data(CRTReg::UnderlineLocation) & 0x1F) as usize;
+
+    let clocking_mode = vga.regs.get_sc_data(SCReg::ClockingMode);
+    let char_width = if clocking_mode & 0x01 != 0 { 8 } else { 9 };
+    let dot_repeat = if clocking_mode & 0x08 != 0 { 2 } else { 1 };
+
+    let mode_control = vga.regs.get_attribute_reg(AttributeReg::ModeControl);
+    let line_graphics = mode_control & 0x04 != 0;
+    let blink = mode_control & 0x08 != 0;
+
+    let (map_a, map_b) = get_character_maps_regs(&vga.regs);
 
-    let mut buffer_offset = 0;
-    for _ in 0..((h / max_scan) as usize) {
-        for _ in 0..max_scan {
-            for x_byte in 0..w_bytes {
-                for p in 0..4 {
-                    let v = vga.mem[p][mem_offset + x_byte];
-                    let color = palette_lock[v as usize];
-                    for _ in 0..v_stretch {
-                        // each color part (RGB) contains the high-order 6 bit values.
-                        // To get a "real" RGB value for display the value have to shifted
-                        // by 2 bits (otherwise the color will be dimmed)
-                        buffer.set_rgb(
-                            buffer_offset,
-                            ((color & 0xFF0000) >> 14) as u8,
-                            ((color & 0x00FF00) >> 6) as u8,
-                            ((color & 0x0000FF) << 2) as u8,
-                        );
-                        buffer_offset += T::PIXEL_WIDTH;
-                    }
-                }
+    //the cursor blinks every 16 frames, blinking characters every 32 frames
+    //(frames of the raster clock, not the frames drawn by the host)
+    let frame = vga.raster_counters().frame;
+    let cursor_start = vga.regs.get_crt_data(CRTReg::CursorStart);
+    let cursor_end = (vga.regs.get_crt_data(CRTReg::CursorEnd) & 0x1F) as usize;
+    let cursor_lines = (cursor_start & 0x1F) as usize..=cursor_end;
+    let cursor_on = cursor_start & 0x20 == 0 && frame & 0x08 == 0;
+    let cursor_location = vga.cursor_location();
+    let blink_on = frame & 0x10 == 0;
+    let colors = attribute_colors(vga);
+
+    //pel panning in dots, for 9 dot characters 8 is no shift and 0-7 shift by 1-8 dots
+    let pan = counter.hpan(vga) & 0x0F;
+    let shift = match (char_width, pan) {
+        (9, 0..=7) => pan as usize + 1,
+        (9, _) => 0,
+        _ => (pan & 0x07) as usize,
+    };
+    let w_dots = columns * char_width;
+
+    let mem_offset = counter.mem_offset;
+    let scan_line = counter.row_scan;
+    let mut dot: usize = 0;
+    let mut x: usize = 0;
+    //one more character for the dots panned in on the right
+    for col in 0..=columns {
+        let ma = (mem_offset + col / crtc.count_by) & ADDRESS_MASK;
+        let addr = crtc.address(ma, scan_line);
+        let ch = vga.mem[0][addr] as usize;
+        let attr = vga.mem[1][addr | 0x01];
+
+        //attribute bit 3 selects the character map (same map if a 256 char font is loaded)
+        let map = if attr & 0x08 != 0 { map_a } else { map_b };
+        let mut glyph = vga.mem[2][map + ch * 32 + scan_line];
+        if attr & 0x07 == 0x01 && scan_line == underline_location {
+            glyph = 0xFF;
+        }
+        if blink && attr & 0x80 != 0 && !blink_on {
+            glyph = 0x00;
+        }
+        let cursor = cursor_on && ma == cursor_location && cursor_lines.contains(&scan_line);
+
+        let fg = attr & 0x0F;
+        //with blink enabled bit 7 is the blink bit and not part of the background color
+        let bg = if blink { (attr >> 4) & 0x07 } else { attr >> 4 };
+
+        for b in 0..char_width {
+            let set = if cursor {
+                true
+            } else if b < 8 {
+                glyph & (0x80 >> b) != 0
+            } else {
+                //9th dot: repeat the 8th for the line graphic characters, otherwise background
+                line_graphics && (0xC0..=0xDF).contains(&ch) && glyph & 0x01 != 0
+            };
+            dot += 1;
+            if dot <= shift || dot > w_dots + shift {
+                continue;
+            }
+            let color = colors[(if set { fg } else { bg }) as usize];
+            for _ in 0..dot_repeat {
+                let offset = y * pitch + x * T::PIXEL_WIDTH;
+                buffer.set_rgb(offset, color.r, color.g, color.b);
+                x += 1;
             }
         }
-        mem_offset += offset_delta * 2;
     }
 }
 
@@ -128,29 +527,44 @@ fn bit_x(v: u8, v_ix: u8, dst_ix: u8) -> u8 {
     if v & v_ix != 0 { 1 << dst_ix } else { 0 }
 }
 
-fn default_16_color(v: u8) -> RGB {
-    //source: https://wasteland.fandom.com/wiki/EGA_Colour_Palette
-    match v {
-        0x00 => rgb(0x0, 0x0, 0x0),
-        0x01 => rgb(0x0, 0x0, 0xA8),
-        0x02 => rgb(0x0, 0xA8, 0x0),
-        0x03 => rgb(0x0, 0xA8, 0xA8),
-        0x04 => rgb(0xA8, 0x0, 0x0),
-        0x05 => rgb(0xA8, 0x0, 0xA8),
-        0x06 => rgb(0xA8, 0x54, 0x00),
-        0x07 => rgb(0xA8, 0xA8, 0xA8),
-        0x08 => rgb(0x54, 0x54, 0x54),
-        0x09 => rgb(0x54, 0x54, 0xFE),
-        0x0A => rgb(0x54, 0xFE, 0x54),
-        0x0B => rgb(0x54, 0xFE, 0xFE),
-        0x0C => rgb(0xFE, 0x54, 0x54),
-        0x0D => rgb(0xFE, 0x54, 0xFE),
-        0x0E => rgb(0xFE, 0xFE, 0x54),
-        0x0F => rgb(0xFE, 0xFE, 0xFE),
-        _ => panic!("wrong color index: {}", v),
-    }
-}
-
-pub fn is_linear(vmode: u8) -> bool {
-    vmode == 0x13
+/// Converts a DAC entry to RGB
+fn dac_color(color: u32) -> RGB {
+    // each color part (RGB) contains the high-order 6 bit values.
+    // To get a "real" RGB value for display the value have to shifted
+    // by 2 bits (otherwise the color will be dimmed)
+    rgb(
+        ((color & 0xFF0000) >> 14) as u8,
+        ((color & 0x00FF00) >> 6) as u8,
+        ((color & 0x0000FF) << 2) as u8,
+    )
+}
+
+/// The colors of the 16 pixel values after the attribute controller (color plane enable,
+/// palette registers and color select) and the DAC (pixel mask and color table)
+fn attribute_colors(vga: &VGAEmu) -> [RGB; 16] {
+    let plane_enable = vga.regs.get_attribute_reg(AttributeReg::ColorPlaneEnable) & 0x0F;
+    let mode_control = vga.regs.get_attribute_reg(AttributeReg::ModeControl);
+    let color_select = vga
+        .regs
+        .get_attribute_reg(AttributeReg::ColorPlaneEnableVGA);
+    let palette = vga.get_palette_256();
+    let pel_mask = vga.regs.get_pel_mask();
+    std::array::from_fn(|pixel| {
+        let mut ix = vga.regs.get_attribute_palette(pixel as u8 & plane_enable) & 0x3F;
+        if mode_control & 0x80 != 0 {
+            //P5-4 select: bits 4-5 from the color select register instead of the palette
+            ix = (ix & 0x0F) | ((color_select & 0x03) << 4);
+        }
+        ix |= (color_select & 0x0C) << 4;
+        dac_color(palette[(ix & pel_mask) as usize])
+    })
+}
+
+/// 8 bit color mode (AttributeReg::ModeControl bit 6), independent of the BIOS mode number
+pub fn is_linear(vga: &VGAEmu) -> bool {
+    vga.regs.get_attribute_reg(AttributeReg::ModeControl) & 0x40 != 0
+}
+
+pub fn is_text(vga: &VGAEmu) -> bool {
+    vga.regs.get_attribute_reg(AttributeReg::ModeControl) & 0x01 == 0
 }
@@ -1,6 +1,4 @@
 use std::sync::{RwLock, RwLockWriteGuard};
-use std::thread::sleep;
-use std::time::Duration;
 
 use sdl2::{
     EventPump,
@@ -11,10 +9,9 @@ use sdl2::{
     video::Window,
 };
 
-use crate::backend::{EmuInput, PixelBuffer, is_linear, render_linear, render_planar};
+use crate::backend::{EmuInput, PixelBuffer, render};
 use crate::input::{InputMonitoring, MouseButton, NumCode};
-use crate::util::{set_de, set_vr};
-use crate::{CRTReg, VERTICAL_RESET_MICRO, VGABuilder, VGAEmu};
+use crate::{VGABuilder, VGAEmu};
 
 pub struct RenderContext {
     canvas: Canvas<Window>,
@@ -22,7 +19,6 @@ pub struct RenderContext {
     event_pump: EventPump,
     height: usize,
     fullscreen: bool,
-    simulate_vertical_reset: bool,
     input_monitoring: RwLock<InputMonitoring>,
 }
 
@@ -57,58 +53,26 @@ impl RenderContext {
             event_pump,
             height,
             fullscreen: builder.fullscreen,
-            simulate_vertical_reset: builder.simulate_vertical_reset,
             input_monitoring: RwLock::new(InputMonitoring::new()),
         })
     }
 
     pub fn draw_frame(&mut self, vga: &mut VGAEmu) -> bool {
-        let offset_delta = vga.regs.get_crt_data(CRTReg::Offset) as usize;
-        if offset_delta == 0 {
-            panic!("illegal CRT offset: {}", offset_delta);
-        }
-
-        let vmode = vga.regs.get_video_mode();
-        let linear = is_linear(vmode);
-        //TODO: inaccurate and currently a hack. This must be somehow inferred from the register states
-        //but I haven't figured out how yet
-        let v_stretch = if vmode == 0x13 { 2 } else { 1 };
-        let mem_offset = vga.mem_offset();
-
-        set_de(vga, true); //display enable is currently only set for whole frame (not toggled for horizontal retrace)
         self.texture
             .with_lock(None, |buffer: &mut [u8], pitch: usize| {
-                if linear {
-                    render_linear(
-                        vga,
-                        mem_offset,
-                        offset_delta,
-                        self.height,
-                        v_stretch,
-                        buffer,
-                    );
-                } else {
-                    render_planar(vga, mem_offset, offset_delta, self.height, buffer, pitch);
-                }
+                render(vga, self.height, buffer, pitch);
             })
             .expect("SDL texture lock");
 
         self.canvas.clear();
         self.canvas.copy(&self.texture, None, None).expect("copy");
         self.canvas.present();
-        set_de(vga, false);
 
         let (emu_input, quit) = self.handle_keys();
         if quit {
             return true;
         }
 
-        if self.simulate_vertical_reset {
-            set_vr(vga, true);
-            sleep(Duration::from_micros(VERTICAL_RESET_MICRO));
-            set_vr(vga, false);
-        }
-
         self.toggle_fullscreen(&emu_input);
 
         false
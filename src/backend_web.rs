@@ -4,10 +4,10 @@ use wasm_bindgen::Clamped;
 use wasm_bindgen::prelude::*;
 use web_sys::CanvasRenderingContext2d;
 
-use crate::backend::{PixelBuffer, is_linear, render_linear, render_planar};
+use crate::backend::{PixelBuffer, render};
 use crate::input::{InputMonitoring, NumCode};
-use crate::util::{get_height, get_width, set_de};
-use crate::{CRTReg, VGABuilder, VGAEmu};
+use crate::util::get_frame_size;
+use crate::{VGABuilder, VGAEmu};
 
 pub struct RenderContext {
     ctx: CanvasRenderingContext2d,
@@ -72,29 +72,12 @@ impl RenderContext {
     }
 
     pub fn draw_frame(&self, vga: &mut VGAEmu) -> bool {
-        let w = get_width(&vga);
-        let h = get_height(&vga);
-
-        // TODO vmode, linear, w, h, v_stretch, offset_delta => provide init function for this and remove
-        // code duplication in web and sdl impl!
-        let vmode = vga.get_video_mode();
-        let linear = is_linear(vmode);
-
-        //TODO: inaccurate and currently a hack. This must be somehow inferred from the register states
-        //but I haven't figured out how yet
-        let v_stretch = if vmode == 0x13 { 2 } else { 1 };
-
-        let offset_delta = vga.regs.get_crt_data(CRTReg::Offset) as usize;
-        if offset_delta == 0 {
-            panic!("illegal CRT offset: {}", offset_delta); // TODO don't panic? it is not nice
-        }
+        let (w, h) = get_frame_size(&vga);
 
         let mut buffer = WebBuffer {
             data: vec![0; (w * h * 4) as usize],
         };
 
-        let mem_offset = vga.mem_offset();
-
         for x in 0..100 {
             for y in 0..100 {
                 let red = ((y * w * 4) + x * 4) as usize;
@@ -105,27 +88,12 @@ impl RenderContext {
             }
         }
 
-        set_de(vga, true);
-
-        if linear {
-            render_linear(
-                &vga,
-                mem_offset,
-                offset_delta,
-                h as usize,
-                v_stretch,
-                &mut buffer,
-            );
-        } else {
-            render_planar(
-                vga,
-                mem_offset,
-                offset_delta,
-                h as usize,
-                &mut buffer,
-                w as usize * WebBuffer::PIXEL_WIDTH,
-            );
-        }
+        render(
+            vga,
+            h as usize,
+            &mut buffer,
+            w as usize * WebBuffer::PIXEL_WIDTH,
+        );
 
         let image_data = web_sys::ImageData::new_with_u8_clamped_array(Clamped(&buffer.data), w)
             .expect("image data");
@@ -0,0 +1,146 @@
+// Monotonic time source for the virtual raster clock
+
+#[cfg(not(feature = "web"))]
+use std::time::Instant;
+
+use crate::VGARegs;
+use crate::util::{
+    RasterPosition, RasterTiming, get_raster_timing_regs, get_vertical_retrace_regs,
+};
+
+pub struct Clock {
+    #[cfg(not(feature = "web"))]
+    origin: Instant,
+    #[cfg(feature = "web")]
+    origin: f64,
+    /// time set by the host, replaces the real time
+    external: Option<u64>,
+}
+
+impl Clock {
+    pub(crate) fn new() -> Clock {
+        Clock {
+            origin: now(),
+            external: None,
+        }
+    }
+
+    /// Drives the clock from the host (e.g. the cycle count of a CPU emulator)
+    /// instead of the real time. The clock then only advances with further calls.
+    pub fn set_elapsed_nanos(&mut self, nanos: u64) {
+        self.external = Some(nanos);
+    }
+
+    /// Nanoseconds since the clock was started
+    pub fn elapsed_nanos(&self) -> u64 {
+        if let Some(nanos) = self.external {
+            return nanos;
+        }
+        #[cfg(not(feature = "web"))]
+        {
+            self.origin.elapsed().as_nanos() as u64
+        }
+        #[cfg(feature = "web")]
+        {
+            ((now() - self.origin) * 1_000_000.0) as u64
+        }
+    }
+}
+
+/// Frames and vertical retrace starts/ends the beam completed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct RasterCounters {
+    pub frame: u64,
+    pub starts: u64,
+    pub ends: u64,
+}
+
+/// The beam counters at the last change of the CRTC timing or the vertical
+/// retrace lines. The beam continues at the same scan line with the new timing,
+/// frames and retraces are counted on from there and never go back.
+pub(crate) struct RasterBase {
+    timing: RasterTiming,
+    retrace: (u32, u32),
+    /// clock time of the change
+    nanos: u64,
+    /// time into the frame at the change, with the new timing
+    phase: u64,
+    counters: RasterCounters,
+}
+
+impl RasterBase {
+    pub(crate) fn new(regs: &VGARegs) -> RasterBase {
+        RasterBase {
+            timing: get_raster_timing_regs(regs),
+            retrace: get_vertical_retrace_regs(regs),
+            nanos: 0,
+            phase: 0,
+            counters: RasterCounters {
+                frame: 0,
+                starts: 0,
+                ends: 0,
+            },
+        }
+    }
+
+    /// Time since the start of the frame the base is in
+    fn frame_nanos(&self, nanos: u64) -> u64 {
+        self.phase + nanos.saturating_sub(self.nanos)
+    }
+
+    pub(crate) fn position(&self, nanos: u64) -> RasterPosition {
+        self.timing.position(self.frame_nanos(nanos))
+    }
+
+    pub(crate) fn counters(&self, nanos: u64) -> RasterCounters {
+        let rel = self.frame_nanos(nanos);
+        let (vr_start, vr_end) = self.retrace;
+        //passes before the base are already counted
+        let passes = |line| self.timing.passes(rel, line) - self.timing.passes(self.phase, line);
+        RasterCounters {
+            frame: self.counters.frame + self.timing.frame(rel),
+            starts: self.counters.starts + passes(vr_start),
+            ends: self.counters.ends + passes(vr_end),
+        }
+    }
+
+    /// Takes over changed timing or retrace registers at the time `nanos`
+    pub(crate) fn rebase(&mut self, regs: &VGARegs, nanos: u64) {
+        let timing = get_raster_timing_regs(regs);
+        let retrace = get_vertical_retrace_regs(regs);
+        if timing == self.timing && retrace == self.retrace {
+            return;
+        }
+        let mut counters = self.counters(nanos);
+        let line = self.position(nanos).line;
+        let phase = if line < timing.v_total {
+            let line_dots = (timing.h_total * timing.char_dots) as u64;
+            (line as u64 * line_dots * 1_000_000_000).div_ceil(timing.dot_clock as u64)
+        } else {
+            //the new frame is shorter than the beam position, it starts over
+            counters.frame += 1;
+            0
+        };
+        *self = RasterBase {
+            timing,
+            retrace,
+            nanos,
+            phase,
+            counters,
+        };
+    }
+}
+
+#[cfg(not(feature = "web"))]
+fn now() -> Instant {
+    Instant::now()
+}
+
+/// milliseconds from the browser's high resolution timer
+#[cfg(feature = "web")]
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or(0.0)
+}
@@ -0,0 +1,93 @@
+// Vertical retrace interrupt (IRQ2): set at the start of the vertical retrace,
+// controlled by CRTReg::VerticalRetraceEnd bits 4/5
+
+use crate::{CRTReg, GeneralReg, VGAEmu};
+
+/// CRTReg::VerticalRetraceEnd bit 4: 0 clears the interrupt and keeps it cleared
+const CLEAR_VERTICAL_INTERRUPT: u8 = 0x10;
+/// CRTReg::VerticalRetraceEnd bit 5: 1 disables the interrupt request
+const DISABLE_VERTICAL_INTERRUPT: u8 = 0x20;
+/// GeneralReg::InputStatus0 bit 7: vertical retrace interrupt pending
+const INTERRUPT_PENDING: u8 = 0x80;
+
+pub(crate) struct VerticalInterrupt {
+    pending: bool,
+    /// retrace starts already taken into account
+    starts: u64,
+    handler: Option<Box<dyn FnMut() + Send>>,
+}
+
+impl VerticalInterrupt {
+    pub(crate) fn new() -> VerticalInterrupt {
+        VerticalInterrupt {
+            pending: false,
+            starts: 0,
+            handler: None,
+        }
+    }
+}
+
+impl VGAEmu {
+    /// Registers the handler that is called when the vertical retrace interrupt
+    /// is raised (at the vertical retrace start, if enabled and not held cleared).
+    /// The interrupt is only raised again after it was cleared
+    /// (CRTReg::VerticalRetraceEnd bit 4 written as 0 and then 1).
+    ///
+    /// Delivery is poll driven: the handler is called from port I/O, `render` or
+    /// `poll_vertical_interrupt`, there is no timer. A host whose guest waits for
+    /// the interrupt without port I/O has to call `poll_vertical_interrupt` regularly.
+    pub fn set_vertical_interrupt_handler(&mut self, handler: impl FnMut() + Send + 'static) {
+        self.irq.handler = Some(Box::new(handler));
+    }
+
+    /// Whether a vertical retrace interrupt is pending (GeneralReg::InputStatus0 bit 7)
+    pub fn vertical_interrupt_pending(&self) -> bool {
+        self.irq.pending
+            || (self.retrace_starts() != self.irq.starts
+                && self.regs.get_crt_data(CRTReg::VerticalRetraceEnd) & CLEAR_VERTICAL_INTERRUPT
+                    != 0)
+    }
+
+    /// Raises the interrupt if the beam passed the start of a vertical retrace since
+    /// the last call. Called on port I/O and rendering, a host that waits for the
+    /// interrupt without either has to call it.
+    pub fn poll_vertical_interrupt(&mut self) {
+        let starts = self.retrace_starts();
+        if starts == self.irq.starts {
+            return;
+        }
+        self.irq.starts = starts;
+        let vr_end = self.regs.get_crt_data(CRTReg::VerticalRetraceEnd);
+        if vr_end & CLEAR_VERTICAL_INTERRUPT == 0 || self.irq.pending {
+            return;
+        }
+        self.irq.pending = true;
+        if vr_end & DISABLE_VERTICAL_INTERRUPT == 0
+            && let Some(handler) = self.irq.handler.as_mut()
+        {
+            handler();
+        }
+    }
+
+    /// Takes over a CRTReg::VerticalRetraceEnd write, a 0 in bit 4 clears the interrupt
+    pub(crate) fn set_vertical_retrace_end(&mut self, v: u8) {
+        self.poll_vertical_interrupt();
+        if v & CLEAR_VERTICAL_INTERRUPT == 0 {
+            self.irq.pending = false;
+        }
+    }
+
+    /// GeneralReg::InputStatus0 with the interrupt pending bit
+    pub fn input_status_0(&self) -> u8 {
+        let status = self.regs.get_general_reg(GeneralReg::InputStatus0) & !INTERRUPT_PENDING;
+        if self.vertical_interrupt_pending() {
+            status | INTERRUPT_PENDING
+        } else {
+            status
+        }
+    }
+
+    fn retrace_starts(&self) -> u64 {
+        self.raster_counters().starts
+    }
+}
@@ -11,7 +11,12 @@ pub mod backend_sdl2;
 pub mod backend_test;
 #[cfg(feature = "web")]
 pub mod backend_web;
+pub mod clock;
+pub mod font;
 pub mod input;
+pub mod interrupt;
+pub mod port;
+pub mod raster;
 pub mod util;
 
 #[cfg(feature = "sdl")]
@@ -27,14 +32,30 @@ pub use backend_web::RenderContext;
 use tracing::instrument;
 
 use std::sync::RwLockWriteGuard;
+use std::time::Duration;
 
+use clock::{Clock, RasterBase, RasterCounters};
+use font::Font;
 use input::InputMonitoring;
-use util::{get_height_regs, get_width_regs};
-
+use interrupt::VerticalInterrupt;
+use port::PortState;
+use raster::{DisplayLatch, RasterLog, RegWrite};
+use util::{
+    RasterPosition, RasterTiming, character_map_offset, get_character_maps_regs,
+    get_display_status_regs, get_frame_size, get_memory_map_regs, get_raster_timing_regs,
+    get_vertical_retrace_regs,
+};
+
+#[deprecated(
+    note = "GeneralReg::InputStatus1 follows the virtual raster clock, see VGAEmu::vertical_retrace_time"
+)]
 pub const VERTICAL_RESET_MICRO: u64 = 635;
 
-pub const PLANE_SIZE: usize = 0xFFFF; // 64KiB
+pub const PLANE_SIZE: usize = 0x10000; // 64KiB
+/// Host and CRTC addresses wrap around at the plane size (16 bit)
+pub(crate) const ADDRESS_MASK: usize = PLANE_SIZE - 1;
 
+#[derive(Clone)]
 pub struct VGARegs {
     sc_reg: Vec<u8>,
     gc_reg: Vec<u8>,
@@ -44,7 +65,9 @@ pub struct VGARegs {
     attribute_reg: Vec<u8>,
     color_reg: Vec<u8>,
 
-    color_write_reads: u16,
+    //the RGB component (0-2) of the next DAC data write and read
+    dac_write_component: u16,
+    dac_read_component: u16,
     video_mode: u8,
 }
 
@@ -58,6 +81,13 @@ pub struct VGAEmu {
     palette_256: [u32; 256],
     pub mem: Vec<Vec<u8>>,
     pub start_addr_override: Option<usize>,
+    overscan: bool,
+    port: PortState,
+    clock: Clock,
+    base: RasterBase,
+    raster: Option<RasterLog>,
+    latch: DisplayLatch,
+    irq: VerticalInterrupt,
 }
 
 //Sequence Controller Register
@@ -148,6 +178,7 @@ pub enum ColorReg {
     AddressReadMode = 0x01,
     Data = 0x02,
     State = 0x03,
+    PelMask = 0x04,
 }
 
 impl VGARegs {
@@ -191,6 +222,16 @@ impl VGARegs {
         self.attribute_reg[reg as usize]
     }
 
+    /// Attribute palette register for the color `ix` (0-15)
+    pub fn get_attribute_palette(&self, ix: u8) -> u8 {
+        self.attribute_reg[(ix & 0x0F) as usize]
+    }
+
+    /// The DAC pixel mask (ColorReg::PelMask), applied to the color index before the DAC lookup
+    pub fn get_pel_mask(&self) -> u8 {
+        self.color_reg[ColorReg::PelMask as usize]
+    }
+
     pub fn get_video_mode(&self) -> u8 {
         self.video_mode
     }
@@ -200,8 +241,9 @@ pub struct VGABuilder {
     video_mode: u8,
     fullscreen: bool,
     title: String,
-    simulate_vertical_reset: bool,
     start_addr_override: Option<usize>,
+    overscan: bool,
+    raster_effects: bool,
 }
 
 impl VGABuilder {
@@ -210,8 +252,9 @@ impl VGABuilder {
             video_mode: 0x10,
             fullscreen: true,
             title: "VGA".to_string(),
-            simulate_vertical_reset: false,
             start_addr_override: None,
+            overscan: false,
+            raster_effects: false,
         }
     }
 
@@ -230,20 +273,39 @@ impl VGABuilder {
         self
     }
 
-    /// If activated this will simulate the vertical reset. Some
-    /// programs may use this and observe the corresponding register
-    /// status changes.
-    /// By default this is not enabled.
-    pub fn simulate_vertical_reset(mut self) -> VGABuilder {
-        self.simulate_vertical_reset = true;
+    /// Without effect: the display enable and vertical retrace bits in
+    /// GeneralReg::InputStatus1 always follow the virtual raster clock.
+    #[deprecated(note = "GeneralReg::InputStatus1 always follows the virtual raster clock")]
+    pub fn simulate_vertical_reset(self) -> VGABuilder {
         self
     }
 
+    /// Debugging aid: displays from the given start address, ignoring the
+    /// start address registers and their latching at the vertical retrace.
     pub fn start_addr_override(mut self, over: usize) -> VGABuilder {
         self.start_addr_override = Some(over);
         self
     }
 
+    /// If activated the border around the active display area is rendered
+    /// in the overscan color (AttributeReg::OverscanColor). The border size
+    /// is determined by the CRTC blanking registers.
+    /// By default this is not enabled.
+    pub fn overscan(mut self) -> VGABuilder {
+        self.overscan = true;
+        self
+    }
+
+    /// If activated the register and DAC writes are recorded with the raster
+    /// position they occurred at and the frame is rendered scan line by scan line
+    /// (palette changes, panning or split screens in the middle of a frame).
+    /// The last completed frame of the virtual raster is rendered.
+    /// By default this is not enabled.
+    pub fn raster_effects(mut self) -> VGABuilder {
+        self.raster_effects = true;
+        self
+    }
+
     /// Constructs a VGA depending on the compile options (see
     /// features list for available options)
     pub fn build(self) -> Result<VGA, String> {
@@ -255,8 +317,7 @@ impl VGA {
     pub fn setup(builder: VGABuilder) -> Result<VGA, String> {
         let vga_emu = VGAEmu::new(&builder);
 
-        let width = get_width_regs(&vga_emu.regs);
-        let height = get_height_regs(&vga_emu.regs);
+        let (width, height) = get_frame_size(&vga_emu);
         let rc = RenderContext::init(width as usize, height as usize, builder)?;
 
         Ok(VGA { vga_emu, rc })
@@ -267,7 +328,7 @@ impl VGA {
     }
 
     pub fn set_sc_data(&mut self, reg: SCReg, v: u8) {
-        self.vga_emu.regs.set_sc_data(reg, v);
+        self.vga_emu.write_reg(RegWrite::Sc(reg as u8, v));
     }
 
     pub fn get_sc_data(&self, reg: SCReg) -> u8 {
@@ -275,7 +336,7 @@ impl VGA {
     }
 
     pub fn set_gc_data(&mut self, reg: GCReg, v: u8) {
-        self.vga_emu.regs.set_gc_data(reg, v);
+        self.vga_emu.write_reg(RegWrite::Gc(reg as u8, v));
     }
 
     pub fn get_gc_data(&self, reg: GCReg) -> u8 {
@@ -284,7 +345,7 @@ impl VGA {
 
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
     pub fn set_crt_data(&mut self, reg: CRTReg, v: u8) {
-        self.vga_emu.regs.set_crt_data(reg, v);
+        self.vga_emu.write_reg(RegWrite::Crt(reg as u8, v));
     }
 
     pub fn get_crt_data(&self, reg: CRTReg) -> u8 {
@@ -292,15 +353,21 @@ impl VGA {
     }
 
     pub fn set_general_reg(&mut self, reg: GeneralReg, v: u8) {
-        self.vga_emu.regs.set_general_reg(reg, v);
+        self.vga_emu.write_reg(RegWrite::General(reg as u8, v));
     }
 
+    /// GeneralReg::InputStatus1 reflects the current raster position,
+    /// GeneralReg::InputStatus0 the vertical retrace interrupt
     pub fn get_general_reg(&self, reg: GeneralReg) -> u8 {
-        self.vga_emu.regs.get_general_reg(reg)
+        match reg {
+            GeneralReg::InputStatus0 => self.vga_emu.input_status_0(),
+            GeneralReg::InputStatus1 => self.vga_emu.input_status_1(),
+            _ => self.vga_emu.regs.get_general_reg(reg),
+        }
     }
 
     pub fn set_attribute_reg(&mut self, reg: AttributeReg, v: u8) {
-        self.vga_emu.regs.set_attribute_reg(reg, v);
+        self.vga_emu.write_reg(RegWrite::Attribute(reg as u8, v));
     }
 
     pub fn get_attribute_reg(&self, reg: AttributeReg) -> u8 {
@@ -331,6 +398,52 @@ impl VGA {
         self.vga_emu.read_mem(offset)
     }
 
+    pub fn write_host(&mut self, addr: usize, v_in: u8) -> bool {
+        self.vga_emu.write_host(addr, v_in)
+    }
+
+    pub fn read_host(&mut self, addr: usize) -> Option<u8> {
+        self.vga_emu.read_host(addr)
+    }
+
+    pub fn port_out(&mut self, port: u16, v: u8) {
+        self.vga_emu.port_out(port, v)
+    }
+
+    pub fn set_raster_time(&mut self, nanos: u64) {
+        self.vga_emu.set_raster_time(nanos)
+    }
+
+    /// See VGAEmu::set_vertical_interrupt_handler, the handler is only called
+    /// when the interrupt is polled (port I/O, draw_frame, poll_vertical_interrupt)
+    pub fn set_vertical_interrupt_handler(&mut self, handler: impl FnMut() + Send + 'static) {
+        self.vga_emu.set_vertical_interrupt_handler(handler)
+    }
+
+    pub fn refresh_rate(&self) -> f64 {
+        self.vga_emu.refresh_rate()
+    }
+
+    pub fn frame_time(&self) -> Duration {
+        self.vga_emu.frame_time()
+    }
+
+    pub fn vertical_interrupt_pending(&self) -> bool {
+        self.vga_emu.vertical_interrupt_pending()
+    }
+
+    pub fn poll_vertical_interrupt(&mut self) {
+        self.vga_emu.poll_vertical_interrupt()
+    }
+
+    pub fn port_out16(&mut self, port: u16, v: u16) {
+        self.vga_emu.port_out16(port, v)
+    }
+
+    pub fn port_in(&mut self, port: u16) -> u8 {
+        self.vga_emu.port_in(port)
+    }
+
     pub fn raw_read_mem(&mut self, plane: usize, offset: usize) -> u8 {
         self.vga_emu.raw_read_mem(plane, offset)
     }
@@ -344,6 +457,14 @@ impl VGA {
         self.vga_emu.write_mem_chunk(offset, v)
     }
 
+    pub fn load_font(&mut self, font: &Font) {
+        self.vga_emu.load_font(font)
+    }
+
+    pub fn load_font_map(&mut self, font: &Font, map: u8) {
+        self.vga_emu.load_font_map(font, map)
+    }
+
     pub fn input_monitoring<'a>(&'a mut self) -> RwLockWriteGuard<'a, InputMonitoring> {
         self.rc.input_monitoring()
     }
@@ -367,14 +488,24 @@ impl VGAEmu {
             attribute_reg: vec![0; 21],
 
             video_mode: builder.video_mode,
-            color_write_reads: 0,
-            color_reg: vec![0; 4],
+            dac_write_component: 0,
+            dac_read_component: 0,
+            color_reg: vec![0; 5],
         };
 
         setup_defaults(&mut regs);
 
         match builder.video_mode {
+            0x00 | 0x01 => setup_mode_01(&mut regs),
+            0x02 | 0x03 => setup_mode_03(&mut regs),
+            0x04 | 0x05 => setup_mode_04(&mut regs),
+            0x06 => setup_mode_06(&mut regs),
+            0x07 => setup_mode_07(&mut regs),
+            0x0D => setup_mode_0d(&mut regs),
+            0x0E => setup_mode_0e(&mut regs),
             0x10 => setup_mode_10(&mut regs),
+            0x11 => setup_mode_11(&mut regs),
+            0x12 => setup_mode_12(&mut regs),
             0x13 => setup_mode_13(&mut regs),
             _ => panic!(
                 "video mode {:x}h not yet implemented",
@@ -382,48 +513,99 @@ impl VGAEmu {
             ),
         }
 
-        VGAEmu {
+        let latch = DisplayLatch::new(&regs);
+        let base = RasterBase::new(&regs);
+        let mut emu = VGAEmu {
             regs,
-            palette_256: init_default_256_palette(),
+            palette_256: init_palette(builder.video_mode),
             mem,
             start_addr_override: builder.start_addr_override,
+            overscan: builder.overscan,
+            port: PortState::new(),
+            clock: Clock::new(),
+            base,
+            raster: None,
+            latch,
+            irq: VerticalInterrupt::new(),
+        };
+
+        if matches!(builder.video_mode, 0x00..=0x03 | 0x07) {
+            emu.load_font(&Font::vga_8x16());
+        }
+        if builder.raster_effects {
+            emu.raster = Some(emu.new_raster_log());
+        }
+
+        emu
+    }
+
+    /// Loads the font into the character maps selected by SCReg::CharacterMapSelect.
+    /// The glyphs 0-255 are loaded into map B and the glyphs 256-511 of a 512 character
+    /// font into map A (selected by attribute bit 3). A 256 character font is loaded
+    /// into both maps.
+    pub fn load_font(&mut self, font: &Font) {
+        let (map_a, map_b) = get_character_maps_regs(&self.regs);
+        font.write_map(&mut self.mem[2], map_b, 0);
+        if font.glyph_count() > 256 {
+            font.write_map(&mut self.mem[2], map_a, 256);
+        } else if map_a != map_b {
+            font.write_map(&mut self.mem[2], map_a, 0);
         }
     }
 
+    /// Loads the first 256 glyphs of the font into the character map `map` (0-7)
+    pub fn load_font_map(&mut self, font: &Font, map: u8) {
+        font.write_map(&mut self.mem[2], character_map_offset(map), 0);
+    }
+
     pub fn set_color_reg(&mut self, reg: ColorReg, v: u8) {
-        self.regs.color_reg[reg as usize] = v;
-        if reg == ColorReg::Data {
-            let writes = self.regs.color_write_reads;
-            self.regs.color_write_reads += 1;
-            let ix = self.get_color_reg(ColorReg::AddressWriteMode) as usize;
-            let color_part_shift = (2 - writes) * 8;
-
-            self.palette_256[ix] &= !((0xFF as u32) << color_part_shift);
-            self.palette_256[ix] |= ((v & 0x3F) as u32) << color_part_shift;
-
-            if writes == 2 {
-                self.regs.color_reg[ColorReg::AddressWriteMode as usize] =
-                    self.regs.color_reg[ColorReg::AddressWriteMode as usize].wrapping_add(1);
-                self.regs.color_write_reads = 0;
+        match reg {
+            ColorReg::Data => {
+                let writes = self.regs.dac_write_component;
+                self.regs.dac_write_component += 1;
+                let ix = self.get_color_reg(ColorReg::AddressWriteMode) as usize;
+                let color_part_shift = (2 - writes) * 8;
+
+                let mut color = self.palette_256[ix] & !((0xFF as u32) << color_part_shift);
+                color |= ((v & 0x3F) as u32) << color_part_shift;
+                self.write_reg(RegWrite::Dac(ix as u8, color));
+
+                if writes == 2 {
+                    self.regs.color_reg[ColorReg::AddressWriteMode as usize] =
+                        self.regs.color_reg[ColorReg::AddressWriteMode as usize].wrapping_add(1);
+                    self.regs.dac_write_component = 0;
+                }
+            }
+            //the DAC state is read only, it follows the last index written
+            ColorReg::State => {}
+            ColorReg::AddressWriteMode => {
+                self.regs.color_reg[reg as usize] = v;
+                self.regs.color_reg[ColorReg::State as usize] = 0x00;
+                self.regs.dac_write_component = 0;
             }
+            ColorReg::AddressReadMode => {
+                self.regs.color_reg[reg as usize] = v;
+                self.regs.color_reg[ColorReg::State as usize] = 0x03;
+                self.regs.dac_read_component = 0;
+            }
+            ColorReg::PelMask => self.write_reg(RegWrite::PelMask(v)),
         }
     }
 
     pub fn get_color_reg(&mut self, reg: ColorReg) -> u8 {
         if reg == ColorReg::Data {
-            let reads = self.regs.color_write_reads;
-            self.regs.color_write_reads += 1;
+            let reads = self.regs.dac_read_component;
+            self.regs.dac_read_component += 1;
             let ix = self.get_color_reg(ColorReg::AddressReadMode) as usize;
             let color_part_shift = (2 - reads) * 8;
             let color = self.get_color_palette_256_value(ix);
-            let word = color & (0xFF as u32) << color_part_shift;
 
             if reads == 2 {
                 self.regs.color_reg[ColorReg::AddressReadMode as usize] =
                     self.regs.color_reg[ColorReg::AddressReadMode as usize].wrapping_add(1);
-                self.regs.color_write_reads = 0;
+                self.regs.dac_read_component = 0;
             }
-            (word >> color_part_shift) as u8
+            ((color >> color_part_shift) & 0x3F) as u8
         } else {
             self.regs.color_reg[reg as usize]
         }
@@ -442,34 +624,136 @@ impl VGAEmu {
         self.regs.get_video_mode()
     }
 
+    /// Sets the time of the raster clock in nanoseconds since the VGA was set up.
+    /// From then on the clock is driven by the host (e.g. from the cycle count
+    /// of a CPU emulator) and not by the real time.
+    pub fn set_raster_time(&mut self, nanos: u64) {
+        self.clock.set_elapsed_nanos(nanos);
+    }
+
+    /// The CRTC timing from the clock select, the clocking mode and the total registers
+    pub fn raster_timing(&self) -> RasterTiming {
+        get_raster_timing_regs(&self.regs)
+    }
+
+    /// Frames per second of the current video mode
+    pub fn refresh_rate(&self) -> f64 {
+        self.raster_timing().refresh_rate()
+    }
+
+    /// Duration of one frame of the current video mode
+    pub fn frame_time(&self) -> Duration {
+        Duration::from_nanos(self.raster_timing().frame_nanos())
+    }
+
+    /// Duration of the vertical retrace of the current video mode
+    pub fn vertical_retrace_time(&self) -> Duration {
+        let (vr_start, vr_end) = get_vertical_retrace_regs(&self.regs);
+        Duration::from_nanos(self.raster_timing().line_nanos() * (vr_end - vr_start) as u64)
+    }
+
+    /// Current position of the raster beam, derived from the time since
+    /// the VGA was set up and the CRTC timing
+    pub fn raster_position(&self) -> RasterPosition {
+        self.base.position(self.clock.elapsed_nanos())
+    }
+
+    /// Frames and vertical retraces the beam completed up to now
+    pub(crate) fn raster_counters(&self) -> RasterCounters {
+        self.base.counters(self.clock.elapsed_nanos())
+    }
+
+    /// GeneralReg::InputStatus1 with the display disabled and vertical retrace
+    /// bits of the current raster position
+    pub fn input_status_1(&self) -> u8 {
+        let status = get_display_status_regs(&self.regs, self.raster_position());
+        (self.regs.get_general_reg(GeneralReg::InputStatus1) & 0xF6) | status
+    }
+
     /// Update VGA memory (destination depends on register state SCReg::MapMask)
     pub fn write_mem(&mut self, offset: usize, v_in: u8) {
+        let offset = offset & ADDRESS_MASK;
         let mem_mode = self.regs.get_sc_data(SCReg::MemoryMode);
+        let plane_offset = chain_4_offset(mem_mode, offset);
 
         let dest = if mem_mode & 0x08 != 0 {
-            //if chain4 is enabled write to all planes
-            0x0F
+            //if chain4 is enabled the lower 2 bits of the offset select the plane
+            self.regs.get_sc_data(SCReg::MapMask) & (1 << (offset & 0x03))
         } else if mem_mode & 0x04 == 0 {
             //odd/even enabled, determine plane on odd/even address
-            if offset % 2 == 0 { 0x05 } else { 0x0A }
+            let map_mask = self.regs.get_sc_data(SCReg::MapMask);
+            if offset % 2 == 0 {
+                map_mask & 0x05
+            } else {
+                map_mask & 0x0A
+            }
         } else {
             self.regs.get_sc_data(SCReg::MapMask)
         };
 
-        let mut gc_mode = self.regs.get_gc_data(GCReg::GraphicsMode);
+        let gc_mode = self.regs.get_gc_data(GCReg::GraphicsMode) & 0x03;
         let bit_mask = self.regs.get_gc_data(GCReg::BitMask);
-        gc_mode &= 0x03;
+        let set_reset = self.regs.get_gc_data(GCReg::SetReset);
+        let enable_set_reset = self.regs.get_gc_data(GCReg::EnableSetReset);
+        let data_rotate = self.regs.get_gc_data(GCReg::DataRotate);
+        let rotate = (data_rotate & 0x07) as u32;
+        let func = (data_rotate >> 3) & 0x03;
 
         for i in 0..4 {
             if (dest & (1 << i)) != 0 {
-                let v = if gc_mode == 0x01 {
-                    self.regs.latch_reg[i]
-                } else {
-                    let v_latch = self.regs.latch_reg[i];
-                    v_in & bit_mask | (v_latch & !bit_mask)
+                let v_latch = self.regs.latch_reg[i];
+                let v = match gc_mode {
+                    0x01 => v_latch,
+                    0x02 => {
+                        //the lower 4 bits of the CPU byte are the color, each bit expanded to its plane
+                        let color = logical_op(func, expand_bit(v_in, i), v_latch);
+                        color & bit_mask | (v_latch & !bit_mask)
+                    }
+                    0x03 => {
+                        //the rotated CPU byte ANDed with the bit mask selects the bits set to the Set/Reset color
+                        let mask = v_in.rotate_right(rotate) & bit_mask;
+                        let color = logical_op(func, expand_bit(set_reset, i), v_latch);
+                        color & mask | (v_latch & !mask)
+                    }
+                    _ => {
+                        let data = if enable_set_reset & (1 << i) != 0 {
+                            expand_bit(set_reset, i)
+                        } else {
+                            v_in.rotate_right(rotate)
+                        };
+                        let v = logical_op(func, data, v_latch);
+                        v & bit_mask | (v_latch & !bit_mask)
+                    }
                 };
-                self.mem[i][offset] = v;
+                self.mem[i][plane_offset] = v;
+            }
+        }
+    }
+
+    /// Memory write to the physical (real mode) address `addr`. Returns false
+    /// if the address is not decoded by the memory map (GCReg::MiscGraphics).
+    pub fn write_host(&mut self, addr: usize, v_in: u8) -> bool {
+        match self.host_offset(addr) {
+            Some(offset) => {
+                self.write_mem(offset, v_in);
+                true
             }
+            None => false,
+        }
+    }
+
+    /// Memory read from the physical (real mode) address `addr`. Returns None
+    /// if the address is not decoded by the memory map (GCReg::MiscGraphics).
+    pub fn read_host(&mut self, addr: usize) -> Option<u8> {
+        self.host_offset(addr).map(|offset| self.read_mem(offset))
+    }
+
+    fn host_offset(&self, addr: usize) -> Option<usize> {
+        let (start, size) = get_memory_map_regs(&self.regs);
+        if addr >= start && addr < start + size {
+            Some(addr - start)
+        } else {
+            None
         }
     }
 
@@ -491,49 +775,324 @@ impl VGAEmu {
     }
 
     pub fn read_mem(&mut self, offset: usize) -> u8 {
+        let offset = offset & ADDRESS_MASK;
         let mem_mode = self.regs.get_sc_data(SCReg::MemoryMode);
         let select = if mem_mode & 0x08 != 0 {
             //if chain4 is enabled, read from the plan determined by the offsets lower 2 bits
             (offset & 0x03) as usize
+        } else if self.regs.get_gc_data(GCReg::GraphicsMode) & 0x10 != 0 {
+            //host odd/even, the offset selects between the even and odd plane of the selected pair
+            ((self.regs.get_gc_data(GCReg::ReadMapSelect) & 0x2) as usize) | (offset & 0x01)
         } else {
             (self.regs.get_gc_data(GCReg::ReadMapSelect) & 0x3) as usize
         };
+        let plane_offset = chain_4_offset(mem_mode, offset);
         for i in 0..4 {
-            self.regs.latch_reg[i] = self.mem[i][offset];
+            self.regs.latch_reg[i] = self.mem[i][plane_offset];
         }
+
+        if self.regs.get_gc_data(GCReg::GraphicsMode) & 0x08 != 0 {
+            //read mode 1: a bit is set for every pixel that matches the compare color
+            //in all planes that are not excluded by the don't care register
+            let color_compare = self.regs.get_gc_data(GCReg::ColorCompare);
+            let color_dont_care = self.regs.get_gc_data(GCReg::ColorDontCare);
+            let mut result = 0xFF;
+            for i in 0..4 {
+                if color_dont_care & (1 << i) != 0 {
+                    result &= !(self.regs.latch_reg[i] ^ expand_bit(color_compare, i));
+                }
+            }
+            return result;
+        }
+
         self.regs.latch_reg[select]
     }
 
+    /// The display start address: the start address registers as latched at the
+    /// start of the last vertical retrace (or the start_addr_override)
     pub fn mem_offset(&self) -> usize {
         if let Some(over) = self.start_addr_override {
             return over;
         }
 
-        let low = self.regs.get_crt_data(CRTReg::StartAdressLow) as u16;
-        let mut addr = self.regs.get_crt_data(CRTReg::StartAdressHigh) as u16;
+        self.latched_start_addr()
+    }
+
+    /// Character address of the text cursor
+    pub fn cursor_location(&self) -> usize {
+        let low = self.regs.get_crt_data(CRTReg::CursorLocaionLow) as u16;
+        let mut addr = self.regs.get_crt_data(CRTReg::CursorLocationHigh) as u16;
         addr <<= 8;
         addr |= low;
         addr as usize
     }
 }
 
+/// The plane offset of a host offset. With chain 4 (SCReg::MemoryMode bit 3) the lower
+/// 2 bits select the plane and are replaced by the offset bits 14/15 in the plane offset
+/// (as the CRTC doubleword mode scans it).
+fn chain_4_offset(mem_mode: u8, offset: usize) -> usize {
+    if mem_mode & 0x08 != 0 {
+        (offset & !0x03) | ((offset >> 14) & 0x03)
+    } else {
+        offset
+    }
+}
+
+/// Expands bit `plane` of `v` to a full byte (0x00 or 0xFF)
+fn expand_bit(v: u8, plane: usize) -> u8 {
+    if v & (1 << plane) != 0 { 0xFF } else { 0x00 }
+}
+
+/// Applies the logical function selected in GCReg::DataRotate (bits 3-4)
+/// to the data and the latched value
+fn logical_op(func: u8, v: u8, v_latch: u8) -> u8 {
+    match func {
+        0x01 => v & v_latch,
+        0x02 => v | v_latch,
+        0x03 => v ^ v_latch,
+        _ => v,
+    }
+}
+
+//standard EGA colors for the 16 color modes (rgbRGB)
+const PALETTE_16: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x14, 0x07, 0x38, 0x39, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x3F,
+];
+
+//monochrome attributes for mode 07h: video (08h) and intensity (10h)
+const PALETTE_MONO: [u8; 16] = [
+    0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x10, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18,
+];
+
+//CRTC registers of the 40 column text modes (00h, 01h), 16 scan lines per character
+#[rustfmt::skip]
+const CRT_TEXT_40: [u8; 25] = [
+    0x2D, 0x27, 0x28, 0x90, 0x2B, 0xA0, 0xBF, 0x1F,
+    0x00, 0x4F, 0x0D, 0x0E, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x14, 0x1F, 0x96, 0xB9, 0xA3,
+    0xFF,
+];
+
+//CRTC registers of the 80 column text modes (02h, 03h, 07h)
+#[rustfmt::skip]
+const CRT_TEXT_80: [u8; 25] = [
+    0x5F, 0x4F, 0x50, 0x82, 0x55, 0x81, 0xBF, 0x1F,
+    0x00, 0x4F, 0x0D, 0x0E, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x28, 0x1F, 0x96, 0xB9, 0xA3,
+    0xFF,
+];
+
+//CRTC registers of the CGA modes 04h/05h (320x200) and 06h (640x200).
+//Odd lines are read from the second 8K bank (row scan bit 0 replaces address bit 13)
+#[rustfmt::skip]
+const CRT_CGA_320X200: [u8; 25] = [
+    0x2D, 0x27, 0x28, 0x90, 0x2B, 0x80, 0xBF, 0x1F,
+    0x00, 0xC1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x14, 0x00, 0x96, 0xB9, 0xA2,
+    0xFF,
+];
+
+#[rustfmt::skip]
+const CRT_CGA_640X200: [u8; 25] = [
+    0x5F, 0x4F, 0x50, 0x82, 0x54, 0x80, 0xBF, 0x1F,
+    0x00, 0xC1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x28, 0x00, 0x96, 0xB9, 0xC2,
+    0xFF,
+];
+
+//CRTC registers of the 320x200 mode (0Dh), 200 lines scan doubled to 400
+#[rustfmt::skip]
+const CRT_320X200: [u8; 25] = [
+    0x2D, 0x27, 0x28, 0x90, 0x2B, 0x80, 0xBF, 0x1F,
+    0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x14, 0x00, 0x96, 0xB9, 0xE3,
+    0xFF,
+];
+
+//CRTC registers of the 640x200 mode (0Eh), 200 lines scan doubled to 400
+#[rustfmt::skip]
+const CRT_640X200: [u8; 25] = [
+    0x5F, 0x4F, 0x50, 0x82, 0x54, 0x80, 0xBF, 0x1F,
+    0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x28, 0x00, 0x96, 0xB9, 0xE3,
+    0xFF,
+];
+
+//CRTC registers of the 640x350 mode (10h)
+#[rustfmt::skip]
+const CRT_640X350: [u8; 25] = [
+    0x5F, 0x4F, 0x50, 0x82, 0x54, 0x80, 0xBF, 0x1F,
+    0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x83, 0x85, 0x5D, 0x28, 0x0F, 0x63, 0xBA, 0xE3,
+    0xFF,
+];
+
+//CRTC registers of the 256 color mode (13h), 200 lines doubled by 2 scan lines per row
+#[rustfmt::skip]
+const CRT_320X200_256: [u8; 25] = [
+    0x5F, 0x4F, 0x50, 0x82, 0x54, 0x80, 0xBF, 0x1F,
+    0x00, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x9C, 0x8E, 0x8F, 0x28, 0x40, 0x96, 0xB9, 0xA3,
+    0xFF,
+];
+
+//CRTC registers of the 640x480 modes (11h, 12h)
+#[rustfmt::skip]
+const CRT_640X480: [u8; 25] = [
+    0x5F, 0x4F, 0x50, 0x82, 0x54, 0x80, 0x0B, 0x3E,
+    0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xEA, 0x8C, 0xDF, 0x28, 0x00, 0xE7, 0x04, 0xE3,
+    0xFF,
+];
+
 fn setup_defaults(regs: &mut VGARegs) {
-    regs.set_crt_data(CRTReg::Offset, 40);
     regs.set_gc_data(GCReg::BitMask, 0xFF);
+    regs.set_attribute_reg(AttributeReg::ColorPlaneEnable, 0x0F);
+    regs.color_reg[ColorReg::PelMask as usize] = 0xFF;
+    regs.attribute_reg[0..16].copy_from_slice(&PALETTE_16);
+}
+
+fn setup_mode_text(regs: &mut VGARegs, crt: &[u8; 25]) {
+    regs.set_general_reg(GeneralReg::MiscOutput, 0x67); //28 MHz clock (720 dots), 400 lines sync polarity
+    regs.set_sc_data(SCReg::MapMask, 0x03); //only planes 0 (character) and 1 (attribute) are writeable
+    regs.set_sc_data(SCReg::MemoryMode, 0x02); //disable chain 4, enable odd/even
+    regs.set_gc_data(GCReg::GraphicsMode, 0x10); //host odd/even
+    regs.crt_reg.copy_from_slice(crt);
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x0C); //blink, line graphics
+    regs.set_attribute_reg(AttributeReg::HorizontalPixelPanning, 0x08); //no shift with 9 dot characters
+}
+
+fn setup_mode_01(regs: &mut VGARegs) {
+    setup_mode_text(regs, &CRT_TEXT_40);
+    regs.set_sc_data(SCReg::ClockingMode, 0x08); //9 dot characters, dot clock / 2
+    regs.set_gc_data(GCReg::MiscGraphics, 0x0E); //text, chain odd/even, B8000h
+}
+
+fn setup_mode_03(regs: &mut VGARegs) {
+    setup_mode_text(regs, &CRT_TEXT_80);
+    regs.set_sc_data(SCReg::ClockingMode, 0x00); //9 dot characters
+    regs.set_gc_data(GCReg::MiscGraphics, 0x0E); //text, chain odd/even, B8000h
+}
+
+fn setup_mode_04(regs: &mut VGARegs) {
+    regs.set_general_reg(GeneralReg::MiscOutput, 0x63);
+    regs.sc_reg.copy_from_slice(&[0x03, 0x09, 0x03, 0x00, 0x02]); //dot clock / 2, odd/even
+    regs.crt_reg.copy_from_slice(&CRT_CGA_320X200);
+    regs.set_gc_data(GCReg::GraphicsMode, 0x30); //shift register interleave, host odd/even
+    regs.set_gc_data(GCReg::MiscGraphics, 0x0F); //graphics, chain odd/even, B8000h
+    regs.set_gc_data(GCReg::ColorDontCare, 0x00);
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x01); //graphics
+    regs.set_attribute_reg(AttributeReg::ColorPlaneEnable, 0x03);
+    //CGA palette 1 (high intensity): black, cyan, magenta, white
+    regs.attribute_reg[0..4].copy_from_slice(&[0x00, 0x3B, 0x3D, 0x3F]);
+}
+
+fn setup_mode_06(regs: &mut VGARegs) {
+    regs.set_general_reg(GeneralReg::MiscOutput, 0x63);
+    regs.sc_reg.copy_from_slice(&[0x03, 0x01, 0x01, 0x00, 0x06]);
+    regs.crt_reg.copy_from_slice(&CRT_CGA_640X200);
+    regs.set_gc_data(GCReg::GraphicsMode, 0x00);
+    regs.set_gc_data(GCReg::MiscGraphics, 0x0D); //graphics, B8000h
+    regs.set_gc_data(GCReg::ColorDontCare, 0x00);
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x01); //graphics
+    regs.set_attribute_reg(AttributeReg::ColorPlaneEnable, 0x01);
+    regs.attribute_reg[1] = 0x3F;
+}
+
+fn setup_mode_07(regs: &mut VGARegs) {
+    setup_mode_text(regs, &CRT_TEXT_80);
+    regs.set_sc_data(SCReg::ClockingMode, 0x00); //9 dot characters
+    regs.set_gc_data(GCReg::MiscGraphics, 0x0A); //text, chain odd/even, B0000h
+    regs.set_crt_data(CRTReg::UnderlineLocation, 0x0F); //last scan line of the 16 line cell
+    regs.attribute_reg[0..16].copy_from_slice(&PALETTE_MONO);
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x0E); //blink, line graphics, mono
+    regs.set_general_reg(GeneralReg::MiscOutput, 0x66); //mono I/O address (3Bxh)
 }
 
 fn setup_mode_10(regs: &mut VGARegs) {
+    regs.set_general_reg(GeneralReg::MiscOutput, 0xA3); //25 MHz clock, 350 lines sync polarity
+    regs.set_sc_data(SCReg::ClockingMode, 0x01); //8 dot characters
     regs.set_sc_data(SCReg::MemoryMode, 0x04); //disable chain 4, disable odd/even
-    regs.set_crt_data(CRTReg::MaximumScanLine, 0x00);
-    set_regs_horizontal_display_end(regs, 640);
-    set_regs_vertical_display_end(regs, 350);
+    regs.crt_reg.copy_from_slice(&CRT_640X350);
+    regs.set_gc_data(GCReg::MiscGraphics, 0x05); //graphics, A0000h (64K)
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x01); //graphics
+}
+
+fn setup_mode_0d(regs: &mut VGARegs) {
+    setup_mode_planar(regs, 0x63, &CRT_320X200);
+    regs.set_sc_data(SCReg::ClockingMode, 0x09); //8 dot characters, dot clock / 2
+}
+
+fn setup_mode_0e(regs: &mut VGARegs) {
+    setup_mode_planar(regs, 0x63, &CRT_640X200);
+}
+
+fn setup_mode_11(regs: &mut VGARegs) {
+    setup_mode_12(regs);
+    regs.set_gc_data(GCReg::MiscGraphics, 0x05); //graphics, A0000h (64K)
+    regs.set_gc_data(GCReg::ColorDontCare, 0x01);
+    //monochrome: only plane 0 is displayed, every color except 0 is white
+    regs.set_attribute_reg(AttributeReg::ColorPlaneEnable, 0x01);
+    regs.attribute_reg[1..16].fill(0x3F);
+}
+
+fn setup_mode_12(regs: &mut VGARegs) {
+    setup_mode_planar(regs, 0xE3, &CRT_640X480); //25 MHz clock, 480 lines sync polarity
+}
+
+/// Register setup shared by the 16 color planar modes (0Dh, 0Eh, 11h, 12h)
+fn setup_mode_planar(regs: &mut VGARegs, misc_output: u8, crt: &[u8; 25]) {
+    regs.set_general_reg(GeneralReg::MiscOutput, misc_output);
+    regs.sc_reg.copy_from_slice(&[0x03, 0x01, 0x0F, 0x00, 0x06]);
+    regs.crt_reg.copy_from_slice(crt);
+    regs.set_gc_data(GCReg::GraphicsMode, 0x00);
+    regs.set_gc_data(GCReg::MiscGraphics, 0x05); //graphics, A0000h (64K)
+    regs.set_gc_data(GCReg::ColorDontCare, 0x0F);
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x01); //graphics
 }
 
 fn setup_mode_13(regs: &mut VGARegs) {
+    regs.set_sc_data(SCReg::ClockingMode, 0x01); //8 dot characters
+    regs.set_sc_data(SCReg::MapMask, 0x0F);
     regs.set_sc_data(SCReg::MemoryMode, 0x08); //enable chain 4, enable odd/even
-    regs.set_crt_data(CRTReg::MaximumScanLine, 0x01);
-    set_regs_horizontal_display_end(regs, 640);
-    set_regs_vertical_display_end(regs, 400);
+    regs.crt_reg.copy_from_slice(&CRT_320X200_256);
+    regs.set_gc_data(GCReg::GraphicsMode, 0x40); //256 color shift mode
+    regs.set_gc_data(GCReg::MiscGraphics, 0x05); //graphics, A0000h (64K)
+    regs.set_attribute_reg(AttributeReg::ModeControl, 0x41); //graphics, 8 bit color
+    regs.set_general_reg(GeneralReg::MiscOutput, 0x63); //25 MHz clock, 400 lines sync polarity
+    for i in 0..16 {
+        regs.attribute_reg[i] = i as u8;
+    }
+}
+
+/// The DAC as loaded by the BIOS. The 16 color modes use the 64 EGA colors
+/// (the attribute palette values index them), mode 07h shades of gray and
+/// mode 13h the default 256 color palette.
+fn init_palette(video_mode: u8) -> [u32; 256] {
+    //the default table holds 8 bit values, the DAC only 6 bit
+    let mut palette = init_default_256_palette().map(|c| (c >> 2) & 0x3F3F3F);
+    if video_mode == 0x07 {
+        for (i, c) in palette.iter_mut().take(64).enumerate() {
+            //the video bit (3) adds 2/3 intensity, the intensity bit (4) 1/3
+            let level = (((i >> 3) & 1) * 0x2A + ((i >> 4) & 1) * 0x15) as u32;
+            *c = level << 16 | level << 8 | level;
+        }
+    } else if video_mode != 0x13 {
+        for (i, c) in palette.iter_mut().take(64).enumerate() {
+            *c = ega_dac_color(i as u8);
+        }
+    }
+    palette
+}
+
+/// DAC entry for the 6 bit EGA color `v` (rgbRGB)
+fn ega_dac_color(v: u8) -> u32 {
+    //the primary bits (0-2) add 2/3 intensity, the secondary bits (3-5) 1/3
+    let level = |primary: u8, secondary: u8| {
+        (((v >> primary) & 1) * 0x2A + ((v >> secondary) & 1) * 0x15) as u32
+    };
+    level(2, 5) << 16 | level(1, 4) << 8 | level(0, 3)
 }
 
 fn init_default_256_palette() -> [u32; 256] {
@@ -573,10 +1132,6 @@ fn init_default_256_palette() -> [u32; 256] {
 
 //convenience functions
 
-fn set_regs_horizontal_display_end(regs: &mut VGARegs, width: u32) {
-    regs.set_crt_data(CRTReg::HorizontalDisplayEnd, ((width - 1) / 8) as u8);
-}
-
 pub fn set_horizontal_display_end(vga: &mut VGA, width: u32) {
     vga.vga_emu
         .regs
@@ -588,8 +1143,8 @@ fn set_regs_vertical_display_end(regs: &mut VGARegs, height: u32) {
     regs.set_crt_data(CRTReg::VerticalDisplayEnd, h as u8);
     let bit_8 = ((h & 0x100) >> 8) as u8;
     let bit_9 = ((h & 0x200) >> 9) as u8;
-    let overflow = bit_9 << 6 | bit_8 << 1;
-    regs.set_crt_data(CRTReg::Overflow, overflow);
+    let overflow = regs.get_crt_data(CRTReg::Overflow) & !0b0100_0010;
+    regs.set_crt_data(CRTReg::Overflow, overflow | bit_9 << 6 | bit_8 << 1);
 }
 
 pub fn set_vertical_display_end(vga: &mut VGA, height: u32) {
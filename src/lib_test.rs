@@ -1,7 +1,16 @@
-use crate::util::{get_height, get_width};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::backend::{PixelBuffer, render, render_linear, render_planar, render_text};
+use crate::font::Font;
+use crate::raster::RegWrite;
+use crate::util::{
+    Overscan, RasterPosition, RasterTiming, get_display_status_regs, get_frame_size, get_height,
+    get_overscan_regs, get_raster_timing_regs, get_width,
+};
 use crate::{
-    ColorReg, GCReg, PLANE_SIZE, SCReg, VGABuilder, set_horizontal_display_end,
-    set_vertical_display_end,
+    AttributeReg, CRTReg, ColorReg, GCReg, GeneralReg, PLANE_SIZE, SCReg, VGA, VGABuilder, VGAEmu,
+    set_horizontal_display_end, set_vertical_display_end,
 };
 
 #[test]
@@ -51,29 +60,302 @@ fn test_write_read_mem_mode_1() -> Result<(), String> {
     Ok(())
 }
 
+#[test]
+fn test_read_mode_1() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    let gc_mode = vga.get_gc_data(GCReg::GraphicsMode);
+    vga.set_gc_data(GCReg::GraphicsMode, gc_mode | 0x08);
+
+    //pixel colors (left to right): 0x0, 0xF, 0x5, 0xA, 0x1, 0x2, 0x4, 0x8
+    vga.raw_write_mem(0, 666, 0b0110_1000);
+    vga.raw_write_mem(1, 666, 0b0101_0100);
+    vga.raw_write_mem(2, 666, 0b0110_0010);
+    vga.raw_write_mem(3, 666, 0b0101_0001);
+
+    vga.set_gc_data(GCReg::ColorDontCare, 0x0F);
+    vga.set_gc_data(GCReg::ColorCompare, 0x05);
+    assert_eq!(vga.read_mem(666), 0b0010_0000);
+    vga.set_gc_data(GCReg::ColorCompare, 0x00);
+    assert_eq!(vga.read_mem(666), 0b1000_0000);
+    vga.set_gc_data(GCReg::ColorCompare, 0x0F);
+    assert_eq!(vga.read_mem(666), 0b0100_0000);
+
+    //only compare plane 0
+    vga.set_gc_data(GCReg::ColorDontCare, 0x01);
+    vga.set_gc_data(GCReg::ColorCompare, 0x01);
+    assert_eq!(vga.read_mem(666), 0b0110_1000);
+
+    //all planes excluded, every pixel matches
+    vga.set_gc_data(GCReg::ColorDontCare, 0x00);
+    assert_eq!(vga.read_mem(666), 0xFF);
+
+    //latches are still loaded
+    vga.set_gc_data(GCReg::GraphicsMode, (gc_mode & 0xFC) | 0x09);
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(888, 0x00);
+    assert_eq!(vga.raw_read_mem(0, 888), 0b0110_1000);
+    assert_eq!(vga.raw_read_mem(1, 888), 0b0101_0100);
+    assert_eq!(vga.raw_read_mem(2, 888), 0b0110_0010);
+    assert_eq!(vga.raw_read_mem(3, 888), 0b0101_0001);
+    Ok(())
+}
+
+#[test]
+fn test_write_mode_0_data_rotate() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    for rotate in 0..8 {
+        vga.set_gc_data(GCReg::DataRotate, rotate);
+        vga.write_mem(666, 0b1000_0001);
+        for p in 0..4 {
+            assert_eq!(
+                vga.raw_read_mem(p, 666),
+                0b1000_0001u8.rotate_right(rotate as u32)
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_write_mode_0_logical_op() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    let latch = 0b1100_1100;
+    let v_in = 0b1010_1010;
+    let cases = [
+        (0x00, v_in),
+        (0x08, v_in & latch),
+        (0x10, v_in | latch),
+        (0x18, v_in ^ latch),
+    ];
+    for (data_rotate, expected) in cases {
+        vga.set_gc_data(GCReg::DataRotate, 0);
+        vga.write_mem(600, latch);
+        vga.read_mem(600); //latch memory
+
+        vga.set_gc_data(GCReg::DataRotate, data_rotate);
+        vga.set_gc_data(GCReg::BitMask, 0xF0);
+        vga.write_mem(666, v_in);
+        vga.set_gc_data(GCReg::BitMask, 0xFF);
+        for p in 0..4 {
+            assert_eq!(
+                vga.raw_read_mem(p, 666),
+                expected & 0xF0 | latch & 0x0F,
+                "data_rotate={:x}",
+                data_rotate
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_write_mode_0_set_reset() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.set_gc_data(GCReg::SetReset, 0b0101);
+    vga.set_gc_data(GCReg::EnableSetReset, 0b0011);
+    vga.write_mem(666, 0x3C);
+    assert_eq!(vga.raw_read_mem(0, 666), 0xFF);
+    assert_eq!(vga.raw_read_mem(1, 666), 0x00);
+    assert_eq!(vga.raw_read_mem(2, 666), 0x3C);
+    assert_eq!(vga.raw_read_mem(3, 666), 0x3C);
+
+    //XOR drawing with set/reset
+    vga.set_gc_data(GCReg::EnableSetReset, 0x0F);
+    vga.set_gc_data(GCReg::SetReset, 0x0F);
+    vga.set_gc_data(GCReg::DataRotate, 0x18);
+    vga.read_mem(666); //latch memory
+    vga.write_mem(666, 0x00); //value doesn't matter
+    assert_eq!(vga.raw_read_mem(0, 666), 0x00);
+    assert_eq!(vga.raw_read_mem(1, 666), 0xFF);
+    assert_eq!(vga.raw_read_mem(2, 666), 0xC3);
+    assert_eq!(vga.raw_read_mem(3, 666), 0xC3);
+    Ok(())
+}
+
+#[test]
+fn test_write_mode_2() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    let gc_mode = vga.get_gc_data(GCReg::GraphicsMode);
+    vga.set_gc_data(GCReg::GraphicsMode, (gc_mode & 0xFC) | 0x02);
+
+    let latch = [0b1100_1010, 0b0101_0011, 0b1111_0000, 0b0000_1111];
+    for map_mask in 0..16u8 {
+        for bit_mask in 0..=255u8 {
+            let color = map_mask ^ 0x05;
+            for (p, l) in latch.iter().enumerate() {
+                vga.raw_write_mem(p, 100, *l);
+                vga.raw_write_mem(p, 200, 0x5A);
+            }
+            vga.read_mem(100); //latch memory
+
+            vga.set_sc_data(SCReg::MapMask, map_mask);
+            vga.set_gc_data(GCReg::BitMask, bit_mask);
+            vga.write_mem(200, 0xF0 | color);
+
+            for (p, l) in latch.iter().enumerate() {
+                let expected = if map_mask & (1 << p) == 0 {
+                    0x5A
+                } else {
+                    let c = if color & (1 << p) != 0 { 0xFF } else { 0x00 };
+                    c & bit_mask | l & !bit_mask
+                };
+                assert_eq!(
+                    vga.raw_read_mem(p, 200),
+                    expected,
+                    "map_mask={:x}, bit_mask={:x}, plane={}",
+                    map_mask,
+                    bit_mask,
+                    p
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_write_mode_3() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    let gc_mode = vga.get_gc_data(GCReg::GraphicsMode);
+    vga.set_gc_data(GCReg::GraphicsMode, (gc_mode & 0xFC) | 0x03);
+
+    let latch = [0b1100_1010, 0b0101_0011, 0b1111_0000, 0b0000_1111];
+    let v_in = 0b1011_0110;
+    for map_mask in 0..16u8 {
+        for bit_mask in 0..=255u8 {
+            let color = map_mask ^ 0x0A;
+            for (p, l) in latch.iter().enumerate() {
+                vga.raw_write_mem(p, 100, *l);
+                vga.raw_write_mem(p, 200, 0x5A);
+            }
+            vga.read_mem(100); //latch memory
+
+            vga.set_sc_data(SCReg::MapMask, map_mask);
+            vga.set_gc_data(GCReg::BitMask, bit_mask);
+            vga.set_gc_data(GCReg::SetReset, color);
+            vga.write_mem(200, v_in);
+
+            for (p, l) in latch.iter().enumerate() {
+                let expected = if map_mask & (1 << p) == 0 {
+                    0x5A
+                } else {
+                    let c = if color & (1 << p) != 0 { 0xFF } else { 0x00 };
+                    let mask = v_in & bit_mask;
+                    c & mask | l & !mask
+                };
+                assert_eq!(
+                    vga.raw_read_mem(p, 200),
+                    expected,
+                    "map_mask={:x}, bit_mask={:x}, plane={}",
+                    map_mask,
+                    bit_mask,
+                    p
+                );
+            }
+        }
+    }
+
+    //the CPU byte is rotated before it is used as a mask
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.set_gc_data(GCReg::BitMask, 0xFF);
+    vga.set_gc_data(GCReg::SetReset, 0x0F);
+    vga.set_gc_data(GCReg::DataRotate, 0x03);
+    for p in 0..4 {
+        vga.raw_write_mem(p, 300, 0);
+    }
+    vga.read_mem(300);
+    vga.write_mem(300, 0b0000_0001);
+    for p in 0..4 {
+        assert_eq!(vga.raw_read_mem(p, 300), 0b0010_0000);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_write_read_chain_4() -> Result<(), String> {
     let mut vga = VGABuilder::new().video_mode(0x13).build()?; //mode 13 has chain4 enabled (also odd/even is enabled but this is ignored if chain4 is enabled)
     for i in 0..PLANE_SIZE {
         vga.write_mem(i, i as u8);
-        for p in 0..4 {
-            //writes to all planes
-            assert_eq!(vga.raw_read_mem(p, i), i as u8);
-        }
+        //the lower 2 bits select the plane and are replaced by the bits 14/15
+        assert_eq!(vga.raw_read_mem(i & 0x03, (i & !0x03) | (i >> 14)), i as u8);
     }
-
     for i in 0..PLANE_SIZE {
-        let plane_ix = (i & 0x3) as usize;
+        //no other plane was overwritten
+        let base = i & !0x03;
         for p in 0..4 {
-            //reset all memory from other planes to check that we read from the right plane
-            if p != plane_ix {
-                vga.raw_write_mem(p, i, 0);
-            }
+            assert_eq!(vga.raw_read_mem(p, base | (i >> 14)), (base + p) as u8);
         }
-        let v = vga.read_mem(i);
-        assert_eq!(v, i as u8);
+        assert_eq!(vga.read_mem(i), i as u8);
     }
 
+    //the map mask still applies
+    vga.set_sc_data(SCReg::MapMask, 0x0E);
+    vga.write_mem(4, 0xAA);
+    vga.write_mem(5, 0xBB);
+    assert_eq!(vga.raw_read_mem(0, 4), 4);
+    assert_eq!(vga.raw_read_mem(1, 4), 0xBB);
+
+    Ok(())
+}
+
+#[test]
+fn test_address_wraparound() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(0xFFFF, 0x80);
+    vga.write_mem(PLANE_SIZE + 1, 0x40); //wraps to 1
+    for p in 0..4 {
+        assert_eq!(vga.raw_read_mem(p, 0xFFFF), 0x80);
+        assert_eq!(vga.raw_read_mem(p, 1), 0x40);
+    }
+    assert_eq!(vga.read_mem(PLANE_SIZE + 0xFFFF), 0x80);
+
+    //the CRTC address wraps at the end of the plane
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0xFFFF, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), white);
+    assert_eq!(buffer.rgb(w, 9, 0), black);
+    assert_eq!(buffer.rgb(w, 17, 0), white);
+    Ok(())
+}
+
+#[test]
+fn test_host_memory_map() -> Result<(), String> {
+    //mode 03: B8000h, odd/even
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    assert!(vga.write_host(0xB8000, b'A'));
+    assert!(vga.write_host(0xB8001, 0x1E));
+    assert_eq!(vga.raw_read_mem(0, 0), b'A');
+    assert_eq!(vga.raw_read_mem(1, 1), 0x1E);
+    assert_eq!(vga.read_host(0xB8001), Some(0x1E));
+    assert!(!vga.write_host(0xA0000, 0xFF));
+    assert!(!vga.write_host(0xC0000, 0xFF));
+    assert_eq!(vga.read_host(0xB7FFF), None);
+
+    //mode 07: B0000h
+    let mut vga = VGABuilder::new().video_mode(0x07).build()?;
+    assert!(vga.write_host(0xB0002, b'B'));
+    assert_eq!(vga.raw_read_mem(0, 2), b'B');
+    assert_eq!(vga.read_host(0xB8000), None);
+
+    //mode 13: A0000h (64K), chain 4
+    let mut vga = VGABuilder::new().video_mode(0x13).build()?;
+    assert!(vga.write_host(0xA0005, 0x42));
+    assert_eq!(vga.raw_read_mem(1, 4), 0x42);
+    assert_eq!(vga.read_host(0xA0005), Some(0x42));
+    assert_eq!(vga.read_host(0xB0000), None);
+
+    //A0000h (128K): B0000h wraps to the plane start
+    vga.set_gc_data(GCReg::MiscGraphics, 0x01);
+    assert!(vga.write_host(0xB0000, 0x43));
+    assert_eq!(vga.raw_read_mem(0, 0), 0x43);
+    assert!(vga.read_host(0xBFFFF).is_some());
     Ok(())
 }
 
@@ -100,6 +382,7 @@ fn test_write_read_odd_even() -> Result<(), String> {
 #[test]
 fn test_bit_mask() -> Result<(), String> {
     let mut vga = VGABuilder::new().video_mode(0x13).build()?; //mode 13 has odd/even enabled
+    vga.set_sc_data(SCReg::MemoryMode, 0x04); //disable chain 4, the map mask selects all planes
     vga.set_sc_data(SCReg::MapMask, 0xFF);
     vga.write_mem(666, 0xFF);
     for i in 0..4 {
@@ -155,10 +438,1356 @@ fn test_set_color() -> Result<(), String> {
 
     assert_eq!(vga.get_color_reg(ColorReg::AddressWriteMode), 1);
     assert_eq!(vga.get_color_palette_256_value(0), 0x3F3E3D);
+    assert_eq!(vga.get_color_reg(ColorReg::State), 0x00);
+
+    Ok(())
+}
+
+#[test]
+fn test_read_color_interleaved() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    vga.set_color_reg(ColorReg::AddressWriteMode, 0x10);
+    vga.set_color_reg(ColorReg::Data, 0x01);
+    vga.set_color_reg(ColorReg::Data, 0x02);
+    vga.set_color_reg(ColorReg::Data, 0x03);
+
+    //read mode
+    vga.set_color_reg(ColorReg::AddressReadMode, 0x10);
+    assert_eq!(vga.get_color_reg(ColorReg::State), 0x03);
+    assert_eq!(vga.port_in(0x3C7), 0x03);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x01);
+
+    //a write in between does not disturb the read sequence (and vice versa)
+    vga.set_color_reg(ColorReg::AddressWriteMode, 0x11);
+    vga.set_color_reg(ColorReg::Data, 0x3F);
+    assert_eq!(vga.get_color_reg(ColorReg::State), 0x00);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x02);
+    vga.set_color_reg(ColorReg::Data, 0x20);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x03);
+    vga.set_color_reg(ColorReg::Data, 0x10);
+    assert_eq!(vga.get_color_palette_256_value(0x11), 0x3F2010);
+
+    //auto-increment, reads return the 6 bit values written
+    assert_eq!(vga.get_color_reg(ColorReg::AddressReadMode), 0x11);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x3F);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x20);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x10);
+
+    //a new read index restarts at the red component
+    vga.set_color_reg(ColorReg::AddressReadMode, 0x10);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x01);
+    vga.set_color_reg(ColorReg::AddressReadMode, 0x10);
+    assert_eq!(vga.get_color_reg(ColorReg::Data), 0x01);
+    Ok(())
+}
+
+struct TestBuffer {
+    data: Vec<u8>,
+}
+
+impl TestBuffer {
+    fn new(w: usize, h: usize) -> TestBuffer {
+        TestBuffer {
+            data: vec![0; w * h * Self::PIXEL_WIDTH],
+        }
+    }
+
+    fn rgb(&self, w: usize, x: usize, y: usize) -> (u8, u8, u8) {
+        let offset = (y * w + x) * Self::PIXEL_WIDTH;
+        (
+            self.data[offset],
+            self.data[offset + 1],
+            self.data[offset + 2],
+        )
+    }
+}
+
+impl PixelBuffer for TestBuffer {
+    const PIXEL_WIDTH: usize = 3;
+    fn set_rgb(&mut self, offset: usize, r: u8, g: u8, b: u8) {
+        self.data[offset] = r;
+        self.data[offset + 1] = g;
+        self.data[offset + 2] = b;
+    }
+}
+
+/// Lets the beam pass a vertical retrace, the start address and pel panning get latched
+fn pass_retrace(vga: &mut VGA) {
+    let nanos = vga.vga_emu.clock.elapsed_nanos() + 20_000_000;
+    vga.set_raster_time(nanos);
+    vga.vga_emu.latch_display();
+}
+
+#[test]
+fn test_text_mode_dimensions() -> Result<(), String> {
+    let vga = VGABuilder::new().video_mode(0x03).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 720);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+
+    let vga = VGABuilder::new().video_mode(0x01).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 720);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+
+    let vga = VGABuilder::new().video_mode(0x07).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 720);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+    Ok(())
+}
+
+#[test]
+fn test_text_mode_odd_even() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    vga.raw_write_mem(2, 0, 0x55);
+    vga.raw_write_mem(2, 1, 0x55);
+    vga.write_mem(0, b'A');
+    vga.write_mem(1, 0x1E);
+    assert_eq!(vga.raw_read_mem(0, 0), b'A');
+    assert_eq!(vga.raw_read_mem(1, 1), 0x1E);
+    //font plane is write protected by the map mask
+    assert_eq!(vga.raw_read_mem(2, 0), 0x55);
+    assert_eq!(vga.raw_read_mem(2, 1), 0x55);
+
+    assert_eq!(vga.read_mem(0), b'A');
+    assert_eq!(vga.read_mem(1), 0x1E);
+    Ok(())
+}
+
+#[test]
+fn test_render_text() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    let ch = 0xC4; //line graphic character
+    for scan_line in 0..16 {
+        vga.raw_write_mem(2, ch * 32 + scan_line, 0b1000_0001);
+    }
+    vga.write_mem(0, ch as u8);
+    vga.write_mem(1, 0x1E); //yellow on blue
+    vga.write_mem(2, b' '); //only background
+    vga.write_mem(3, 0x4F); //white on red
+    vga.set_crt_data(CRTReg::CursorStart, 0x20); //cursor off
+
+    let w = 720;
+    let mut buffer = TestBuffer::new(w, 400);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+
+    let yellow = (0xFC, 0xFC, 0x54);
+    let blue = (0x00, 0x00, 0xA8);
+    let red = (0xA8, 0x00, 0x00);
+    for y in 0..16 {
+        assert_eq!(buffer.rgb(w, 0, y), yellow);
+        for x in 1..7 {
+            assert_eq!(buffer.rgb(w, x, y), blue);
+        }
+        assert_eq!(buffer.rgb(w, 7, y), yellow);
+        //9th dot repeated for line graphic characters
+        assert_eq!(buffer.rgb(w, 8, y), yellow);
+        for x in 9..18 {
+            assert_eq!(buffer.rgb(w, x, y), red);
+        }
+    }
+
+    //line graphics disabled
+    vga.set_attribute_reg(AttributeReg::ModeControl, 0x08);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 7, 0), yellow);
+    assert_eq!(buffer.rgb(w, 8, 0), blue);
+    Ok(())
+}
+
+#[test]
+fn test_render_text_mono() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x07).build()?;
+    let block = 0xDB; //full block
+    for scan_line in 0..16 {
+        vga.raw_write_mem(2, block * 32 + scan_line, 0xFF);
+    }
+    vga.write_mem(0, b' ');
+    vga.write_mem(1, 0x01); //underline
+    vga.write_mem(2, block as u8);
+    vga.write_mem(3, 0x07); //normal
+    vga.write_mem(4, block as u8);
+    vga.write_mem(5, 0x0F); //intense
+    vga.set_crt_data(CRTReg::CursorStart, 0x20); //cursor off
+
+    let w = 720;
+    let mut buffer = TestBuffer::new(w, 400);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    let black = (0x00, 0x00, 0x00);
+    let gray = (0xA8, 0xA8, 0xA8);
+    let white = (0xFC, 0xFC, 0xFC);
+    //underline in the last scan line of the cell, normal intensity
+    assert_eq!(buffer.rgb(w, 0, 14), black);
+    assert_eq!(buffer.rgb(w, 0, 15), gray);
+    assert_eq!(buffer.rgb(w, 8, 15), black);
+    assert_eq!(buffer.rgb(w, 9, 0), gray);
+    assert_eq!(buffer.rgb(w, 18, 0), white);
+    Ok(())
+}
+
+#[test]
+fn test_render_text_pel_panning() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    let ch = 0xC4; //line graphic character
+    for scan_line in 0..16 {
+        vga.raw_write_mem(2, ch * 32 + scan_line, 0b1000_0001);
+    }
+    vga.write_mem(0, ch as u8);
+    vga.write_mem(1, 0x1E); //yellow on blue
+    vga.write_mem(2, b' ');
+    vga.write_mem(3, 0x4F); //white on red
+    vga.set_crt_data(CRTReg::CursorStart, 0x20); //cursor off
+
+    let w = 720;
+    let yellow = (0xFC, 0xFC, 0x54);
+    let blue = (0x00, 0x00, 0xA8);
+    let red = (0xA8, 0x00, 0x00);
+    let mut buffer = TestBuffer::new(w, 400);
+    let mut render_pan = |vga: &mut VGA, pan| {
+        vga.set_attribute_reg(AttributeReg::HorizontalPixelPanning, pan);
+        pass_retrace(vga);
+        render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+        [0, 1, 6, 7, 8].map(|x| buffer.rgb(w, x, 0))
+    };
+    //9 dot characters: 8 is no shift
+    assert_eq!(
+        render_pan(&mut vga, 8),
+        [yellow, blue, blue, yellow, yellow]
+    );
+    //0 shifts by 1 dot, 7 by 8 dots
+    assert_eq!(render_pan(&mut vga, 0), [blue, blue, yellow, yellow, red]);
+    assert_eq!(render_pan(&mut vga, 7), [yellow, red, red, red, red]);
+    //8 dot characters: shift by 0-7 dots
+    vga.set_sc_data(SCReg::ClockingMode, 0x01);
+    assert_eq!(render_pan(&mut vga, 1), [blue, blue, yellow, red, red]);
+    Ok(())
+}
+
+#[test]
+fn test_builtin_fonts() -> Result<(), String> {
+    for (font, height) in [
+        (Font::vga_8x8(), 8),
+        (Font::vga_8x14(), 14),
+        (Font::vga_8x16(), 16),
+    ] {
+        assert_eq!(font.height(), height);
+        assert_eq!(font.glyph_count(), 256);
+        let glyph = |ch| font.glyph(ch).ok_or("glyph missing");
+        assert!(glyph(0)?.iter().all(|r| *r == 0));
+        assert!(glyph(0xDB)?.iter().all(|r| *r == 0xFF)); //full block
+        assert!(glyph(b'A' as usize)?.iter().any(|r| *r != 0));
+        assert_eq!(font.glyph(256), None);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_font_from_raw_and_psf() -> Result<(), String> {
+    let raw: Vec<u8> = (0..256 * 10).map(|i| (i / 10) as u8).collect();
+    let font = Font::from_raw(&raw)?;
+    assert_eq!(font.height(), 10);
+    assert_eq!(font.glyph(0x42), Some(&[0x42; 10][..]));
+    assert!(Font::from_raw(&raw[1..]).is_err());
+
+    let mut psf1 = vec![0x36, 0x04, 0x01, 10];
+    psf1.extend((0..512 * 10).map(|i| (i / 10) as u8));
+    let font = Font::from_psf(&psf1)?;
+    assert_eq!(font.height(), 10);
+    assert_eq!(font.glyph_count(), 512);
+    assert_eq!(font.glyph(0x101), Some(&[0x01; 10][..]));
+    assert_eq!(font.glyph(512), None);
+    assert!(Font::from_psf(&psf1[..100]).is_err());
+
+    let mut psf2 = vec![0x72, 0xB5, 0x4A, 0x86];
+    for v in [0u32, 32, 0, 256, 12, 12, 8] {
+        psf2.extend(v.to_le_bytes());
+    }
+    psf2.extend((0..256 * 12).map(|i| (i / 12) as u8));
+    let font = Font::from_psf(&psf2)?;
+    assert_eq!(font.height(), 12);
+    assert_eq!(font.glyph_count(), 256);
+    assert_eq!(font.glyph(0xFF), Some(&[0xFF; 12][..]));
+    //glyphs with padding bytes (char size != height) are not supported
+    let mut padded = psf2.clone();
+    padded[20] = 24;
+    let err = Font::from_psf(&padded).err().unwrap_or_default();
+    assert!(err.contains("size: 24"), "{}", err);
+    //sizes that overflow are an error, not a panic
+    let mut huge = psf2.clone();
+    huge[8..12].copy_from_slice(&u32::MAX.to_le_bytes()); //header size
+    huge[20..28].copy_from_slice(&[0xFF; 8]); //char size and height
+    assert!(Font::from_psf(&huge).is_err());
+
+    assert!(Font::from_psf(&raw).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_text_mode_default_font() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    let font = Font::vga_8x16();
+    let a = b'A' as usize;
+    for row in 0..32 {
+        let expected = if row < 16 {
+            font.glyph(a).ok_or("glyph missing")?[row]
+        } else {
+            0
+        };
+        assert_eq!(vga.raw_read_mem(2, a * 32 + row), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_load_font_512() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    let mut psf1 = vec![0x36, 0x04, 0x01, 8];
+    psf1.extend((0..512 * 8).map(|i| (i / 8) as u8));
+    let font = Font::from_psf(&psf1)?;
+
+    vga.set_sc_data(SCReg::CharacterMapSelect, 0x04); //map A = 1, map B = 0
+    vga.load_font(&font);
+    assert_eq!(vga.raw_read_mem(2, 0x10 * 32), 0x10);
+    assert_eq!(vga.raw_read_mem(2, 0x10 * 32 + 8), 0x00);
+    assert_eq!(vga.raw_read_mem(2, 0x4000 + 0x10 * 32), 0x10); //glyph 0x110
+
+    vga.load_font_map(&Font::vga_8x8(), 5);
+    let a = b'A' as usize;
+    assert_eq!(
+        vga.raw_read_mem(2, 0x6000 + a * 32 + 1),
+        Font::vga_8x8().glyph(a).ok_or("glyph missing")?[1]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_text_cursor() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    vga.write_mem(2 * 81, b' ');
+    vga.write_mem(2 * 81 + 1, 0x1E); //yellow on blue
+    vga.set_crt_data(CRTReg::CursorLocationHigh, 0);
+    vga.set_crt_data(CRTReg::CursorLocaionLow, 81); //row 1, column 1
+
+    let w = 720;
+    let yellow = (0xFC, 0xFC, 0x54);
+    let blue = (0x00, 0x00, 0xA8);
+    let mut buffer = TestBuffer::new(w, 400);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    for x in 9..18 {
+        assert_eq!(buffer.rgb(w, x, 16 + 12), blue);
+        assert_eq!(buffer.rgb(w, x, 16 + 13), yellow);
+        assert_eq!(buffer.rgb(w, x, 16 + 14), yellow);
+        assert_eq!(buffer.rgb(w, x, 16 + 15), blue);
+    }
+
+    //blinks every 16 frames of the raster clock
+    let frame = vga.frame_time().as_nanos() as u64;
+    vga.set_raster_time(8 * frame + 1_000);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 9, 16 + 13), blue);
+    vga.set_raster_time(16 * frame + 1_000);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 9, 16 + 13), yellow);
+
+    //cursor disabled
+    vga.set_crt_data(CRTReg::CursorStart, 0x20);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 9, 16 + 13), blue);
+    Ok(())
+}
+
+#[test]
+fn test_render_text_blink() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    vga.set_crt_data(CRTReg::CursorStart, 0x20);
+    vga.write_mem(0, 0xDB); //full block
+    vga.write_mem(1, 0x9E); //blinking yellow on blue
+
+    let w = 720;
+    let yellow = (0xFC, 0xFC, 0x54);
+    let blue = (0x00, 0x00, 0xA8);
+    let mut buffer = TestBuffer::new(w, 400);
+    let frame_time = vga.frame_time().as_nanos() as u64;
+    for (frame, expected) in [
+        (0, yellow),
+        (15, yellow),
+        (16, blue),
+        (31, blue),
+        (32, yellow),
+    ] {
+        vga.set_raster_time(frame * frame_time + 1_000);
+        render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+        assert_eq!(buffer.rgb(w, 0, 0), expected, "frame {}", frame);
+    }
+
+    //blink disabled, bit 7 selects the intense background color
+    vga.set_attribute_reg(AttributeReg::ModeControl, 0x04);
+    vga.set_raster_time(16 * frame_time + 1_000);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), yellow);
+    Ok(())
+}
+
+#[test]
+fn test_mode_12() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 640);
+    assert_eq!(get_height(&vga.vga_emu), 480);
+    //25 MHz dot clock
+    assert_eq!(vga.get_general_reg(GeneralReg::MiscOutput) & 0x0C, 0x00);
+
+    //last line, color 0x0E in the first pixel and color 0x06 (brown) in the last pixel
+    vga.set_sc_data(SCReg::MapMask, 0x0E);
+    vga.write_mem(479 * 80, 0x80);
+    vga.set_sc_data(SCReg::MapMask, 0x06);
+    vga.write_mem(479 * 80 + 79, 0x01);
+
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 479), (0xFC, 0xFC, 0x54));
+    assert_eq!(buffer.rgb(w, 1, 479), (0x00, 0x00, 0x00));
+    assert_eq!(buffer.rgb(w, 639, 479), (0xA8, 0x54, 0x00));
+    assert_eq!(buffer.rgb(w, 639, 478), (0x00, 0x00, 0x00));
+    Ok(())
+}
+
+#[test]
+fn test_mode_11() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x11).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 640);
+    assert_eq!(get_height(&vga.vga_emu), 480);
+    //64K at A0000h, B8000h is not decoded
+    assert!(vga.write_host(0xAFFFF, 0x01));
+    assert!(!vga.write_host(0xB8000, 0x01));
+    assert_eq!(vga.read_host(0xB8000), None);
+    assert_eq!(vga.get_attribute_reg(AttributeReg::ColorPlaneEnable), 0x01);
+    assert_eq!(vga.get_gc_data(GCReg::ColorDontCare), 0x01);
 
+    vga.write_mem(80, 0xA0);
+
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 1), (0xFC, 0xFC, 0xFC));
+    assert_eq!(buffer.rgb(w, 1, 1), (0x00, 0x00, 0x00));
+    assert_eq!(buffer.rgb(w, 2, 1), (0xFC, 0xFC, 0xFC));
+    Ok(())
+}
+
+#[test]
+fn test_mode_0d() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x0D).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 640);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+
+    //second line, first pixel white, last pixel red
+    vga.write_mem(40, 0x80);
+    vga.set_sc_data(SCReg::MapMask, 0x04);
+    vga.write_mem(79, 0x01);
+
+    let (w, h) = (640, 400);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 20, h, &mut buffer, w * 3);
+    let white = (0xFC, 0xFC, 0xFC);
+    let red = (0xA8, 0x00, 0x00);
+    //each pixel is doubled horizontally and vertically
+    for y in 2..4 {
+        assert_eq!(buffer.rgb(w, 0, y), white);
+        assert_eq!(buffer.rgb(w, 1, y), white);
+        assert_eq!(buffer.rgb(w, 2, y), (0x00, 0x00, 0x00));
+        assert_eq!(buffer.rgb(w, 638, y), red);
+        assert_eq!(buffer.rgb(w, 639, y), red);
+    }
+    assert_eq!(buffer.rgb(w, 0, 1), (0x00, 0x00, 0x00));
+    assert_eq!(buffer.rgb(w, 0, 4), (0x00, 0x00, 0x00));
+    Ok(())
+}
+
+#[test]
+fn test_mode_0e() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x0E).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 640);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+
+    vga.write_mem(80, 0xC0);
+
+    let (w, h) = (640, 400);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    for y in 2..4 {
+        assert_eq!(buffer.rgb(w, 0, y), (0xFC, 0xFC, 0xFC));
+        assert_eq!(buffer.rgb(w, 1, y), (0xFC, 0xFC, 0xFC));
+        assert_eq!(buffer.rgb(w, 2, y), (0x00, 0x00, 0x00));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_mode_04() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x04).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 640);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+
+    //line 0: pixel 4 cyan (odd byte), line 1: pixel 0 white (second bank)
+    vga.write_mem(1, 0x40);
+    vga.write_mem(0x2000, 0xC0);
+    //line 2: pixel 3 magenta
+    vga.write_mem(80, 0x02);
+
+    let (w, h) = (640, 400);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 20, h, &mut buffer, w * 3);
+    let black = (0x00, 0x00, 0x00);
+    let cyan = (0x54, 0xFC, 0xFC);
+    let magenta = (0xFC, 0x54, 0xFC);
+    let white = (0xFC, 0xFC, 0xFC);
+    for y in 0..2 {
+        assert_eq!(buffer.rgb(w, 0, y), black);
+        assert_eq!(buffer.rgb(w, 8, y), cyan);
+        assert_eq!(buffer.rgb(w, 9, y), cyan);
+        assert_eq!(buffer.rgb(w, 10, y), black);
+    }
+    for y in 2..4 {
+        assert_eq!(buffer.rgb(w, 0, y), white);
+        assert_eq!(buffer.rgb(w, 1, y), white);
+        assert_eq!(buffer.rgb(w, 2, y), black);
+        assert_eq!(buffer.rgb(w, 8, y), black);
+    }
+    for y in 4..6 {
+        assert_eq!(buffer.rgb(w, 6, y), magenta);
+        assert_eq!(buffer.rgb(w, 7, y), magenta);
+        assert_eq!(buffer.rgb(w, 0, y), black);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_mode_06() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x06).build()?;
+    assert_eq!(get_width(&vga.vga_emu), 640);
+    assert_eq!(get_height(&vga.vga_emu), 400);
+
+    vga.write_mem(0, 0x80);
+    vga.write_mem(0x2000 + 79, 0x01);
+
+    let (w, h) = (640, 400);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    let black = (0x00, 0x00, 0x00);
+    let white = (0xFC, 0xFC, 0xFC);
+    for y in 0..2 {
+        assert_eq!(buffer.rgb(w, 0, y), white);
+        assert_eq!(buffer.rgb(w, 1, y), black);
+        assert_eq!(buffer.rgb(w, 639, y), black);
+    }
+    for y in 2..4 {
+        assert_eq!(buffer.rgb(w, 0, y), black);
+        assert_eq!(buffer.rgb(w, 639, y), white);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_render_linear_geometry() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x13).build()?;
+    //pixel (1, 1) in color 0x0F (white)
+    vga.write_mem(320 + 1, 0x0F);
+    assert_eq!(vga.raw_read_mem(1, 320), 0x0F);
+
+    let (w, h) = (640, 400);
+    let mut buffer = TestBuffer::new(w, h);
+    render_linear(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    let black = (0x00, 0x00, 0x00);
+    let white = (0xFC, 0xFC, 0xFC);
+    //doubled in both directions
+    for y in 2..4 {
+        assert_eq!(buffer.rgb(w, 1, y), black);
+        assert_eq!(buffer.rgb(w, 2, y), white);
+        assert_eq!(buffer.rgb(w, 3, y), white);
+        assert_eq!(buffer.rgb(w, 4, y), black);
+    }
+    assert_eq!(buffer.rgb(w, 2, 1), black);
+    assert_eq!(buffer.rgb(w, 2, 4), black);
+
+    //tweaked 360x480: unchained byte mode, 90 character clocks, no line doubling
+    vga.set_crt_data(CRTReg::UnderlineLocation, 0x00);
+    vga.set_crt_data(CRTReg::CRTCModeControl, 0xE3);
+    vga.set_crt_data(CRTReg::HorizontalDisplayEnd, 89);
+    vga.set_crt_data(CRTReg::MaximumScanLine, 0x40);
+    set_vertical_display_end(&mut vga, 480);
+    assert_eq!(get_width(&vga.vga_emu), 720);
+    assert_eq!(get_height(&vga.vga_emu), 480);
+    vga.raw_write_mem(3, 90 * 479 + 89, 0x0F);
+
+    let (w, h) = (720, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    render_linear(&vga.vga_emu, 0, 45, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 2, 1), black);
+    assert_eq!(buffer.rgb(w, 718, 479), white);
+    assert_eq!(buffer.rgb(w, 719, 479), white);
+    assert_eq!(buffer.rgb(w, 717, 479), black);
+    assert_eq!(buffer.rgb(w, 719, 478), black);
+    Ok(())
+}
+
+#[test]
+fn test_crtc_addressing_modes() -> Result<(), String> {
+    let blue = (0x00, 0x00, 0xA8);
+    let black = (0x00, 0x00, 0x00);
+    let (w, h) = (640, 1);
+    //(CRTCModeControl, UnderlineLocation, start address, expected memory address)
+    let cases = [
+        (0xE3, 0x00, 5, 5),           //byte mode
+        (0xA3, 0x00, 5, 10),          //word mode
+        (0xA3, 0x00, 0x8005, 0x000B), //word mode, MA 15 into bit 0
+        (0x83, 0x00, 0x2005, 0x400B), //word mode, MA 13 into bit 0
+        (0xE3, 0x40, 5, 20),          //doubleword mode
+        (0xE3, 0x40, 0x1005, 0x4015), //doubleword mode, MA 12/13 into bit 0/1
+    ];
+    for (crtc_mode, underline, start, addr) in cases {
+        let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+        vga.set_crt_data(CRTReg::CRTCModeControl, crtc_mode);
+        vga.set_crt_data(CRTReg::UnderlineLocation, underline);
+        vga.raw_write_mem(0, addr, 0x80);
+        let mut buffer = TestBuffer::new(w, h);
+        render_planar(&vga.vga_emu, start, 40, h, &mut buffer, w * 3);
+        assert_eq!(buffer.rgb(w, 0, 0), blue, "address {:x}", addr);
+        assert_eq!(buffer.rgb(w, 1, 0), black);
+    }
+
+    //count by 2: the address counter advances every second character clock
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    vga.set_crt_data(CRTReg::CRTCModeControl, 0xEB);
+    vga.raw_write_mem(0, 1, 0x80);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 8, 0), black);
+    assert_eq!(buffer.rgb(w, 16, 0), blue);
+    assert_eq!(buffer.rgb(w, 24, 0), blue);
+    assert_eq!(buffer.rgb(w, 32, 0), black);
+    Ok(())
+}
+
+#[test]
+fn test_line_compare_planar() -> Result<(), String> {
+    let mut vga = VGABuilder::new().start_addr_override(80 * 10).build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(80 * 10, 0x80); //first pixel of the top part
+    vga.write_mem(0, 0x40); //second pixel of the split screen part
+    //line compare 0x105: bit 8 in overflow
+    vga.set_crt_data(CRTReg::LineCompare, 0x05);
+    vga.set_crt_data(CRTReg::Overflow, vga.get_crt_data(CRTReg::Overflow) | 0x10);
+    vga.set_crt_data(CRTReg::MaximumScanLine, 0x00);
+    vga.set_attribute_reg(AttributeReg::HorizontalPixelPanning, 1);
+    pass_retrace(&mut vga);
+
+    let (w, h) = (640, 350);
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 80 * 10, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), black); //panned out
+    assert_eq!(buffer.rgb(w, 0, 0x106), white);
+    assert_eq!(buffer.rgb(w, 0, 0x107), black);
+
+    //PEL panning compatibility: no panning below the split
+    vga.set_attribute_reg(AttributeReg::ModeControl, 0x21);
+    render_planar(&vga.vga_emu, 80 * 10, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), black);
+    assert_eq!(buffer.rgb(w, 0, 0x105), black);
+    assert_eq!(buffer.rgb(w, 0, 0x106), black);
+    assert_eq!(buffer.rgb(w, 1, 0x106), white);
+    Ok(())
+}
+
+#[test]
+fn test_line_compare_linear() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x13).build()?;
+    vga.raw_write_mem(0, 0, 0x0F);
+    vga.raw_write_mem(1, 0, 0x0F);
+    //line compare 99, bit 8 and 9 cleared
+    vga.set_crt_data(CRTReg::LineCompare, 99);
+    vga.set_crt_data(CRTReg::Overflow, vga.get_crt_data(CRTReg::Overflow) & !0x10);
+    vga.set_crt_data(CRTReg::MaximumScanLine, 0x01);
+    vga.set_attribute_reg(AttributeReg::HorizontalPixelPanning, 2);
+    pass_retrace(&mut vga);
+
+    let (w, h) = (640, 400);
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    let mut buffer = TestBuffer::new(w, h);
+    render_linear(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    //panned by one pixel
+    assert_eq!(buffer.rgb(w, 0, 0), white);
+    assert_eq!(buffer.rgb(w, 2, 0), black);
+    assert_eq!(buffer.rgb(w, 0, 99), black);
+    //split screen starts at address 0, with line doubling restarting
+    for y in 100..102 {
+        assert_eq!(buffer.rgb(w, 0, y), white);
+        assert_eq!(buffer.rgb(w, 2, y), black);
+    }
+    assert_eq!(buffer.rgb(w, 0, 102), black);
+
+    vga.set_attribute_reg(AttributeReg::ModeControl, 0x61);
+    render_linear(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), white);
+    assert_eq!(buffer.rgb(w, 2, 0), black);
+    assert_eq!(buffer.rgb(w, 2, 100), white);
+    assert_eq!(buffer.rgb(w, 4, 100), black);
+    Ok(())
+}
+
+#[test]
+fn test_planar_attribute_palette_and_dac() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x03);
+    vga.write_mem(0, 0x80); //color 3
+
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    let mut render = |vga: &mut crate::VGA| {
+        render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+        buffer.rgb(w, 0, 0)
+    };
+    assert_eq!(render(&mut vga), (0x00, 0xA8, 0xA8));
+
+    //DAC fade of the EGA color 3
+    vga.set_color_reg(ColorReg::AddressWriteMode, 0x03);
+    vga.set_color_reg(ColorReg::Data, 0x01);
+    vga.set_color_reg(ColorReg::Data, 0x02);
+    vga.set_color_reg(ColorReg::Data, 0x03);
+    assert_eq!(render(&mut vga), (0x04, 0x08, 0x0C));
+
+    //palette register points color 3 to another DAC entry
+    vga.set_attribute_reg(AttributeReg::Palette3, 0x3F);
+    assert_eq!(render(&mut vga), (0xFC, 0xFC, 0xFC));
+
+    //plane 1 disabled: color 1
+    vga.set_attribute_reg(AttributeReg::ColorPlaneEnable, 0x01);
+    assert_eq!(render(&mut vga), (0x00, 0x00, 0xA8));
+
+    //color select bits 7-6 and (with P5-4 select) bits 5-4 of the DAC index
+    vga.set_color_reg(ColorReg::AddressWriteMode, 0xD1);
+    vga.set_color_reg(ColorReg::Data, 0x3F);
+    vga.set_color_reg(ColorReg::Data, 0x00);
+    vga.set_color_reg(ColorReg::Data, 0x00);
+    vga.set_attribute_reg(AttributeReg::ColorPlaneEnableVGA, 0x0D);
+    vga.set_attribute_reg(AttributeReg::ModeControl, 0x81);
+    assert_eq!(render(&mut vga), (0xFC, 0x00, 0x00));
+    Ok(())
+}
+
+#[test]
+fn test_overscan() -> Result<(), String> {
+    let vga = VGABuilder::new().video_mode(0x03).build()?;
+    let border = get_overscan_regs(&vga.vga_emu.regs);
+    assert_eq!(
+        border,
+        Overscan {
+            left: 18,
+            right: 0,
+            top: 8,
+            bottom: 6
+        }
+    );
+    //disabled by default
+    assert_eq!(get_frame_size(&vga.vga_emu), (720, 400));
+
+    let mut vga = VGABuilder::new().video_mode(0x12).overscan().build()?;
+    assert_eq!(get_frame_size(&vga.vga_emu), (656, 496));
+    vga.set_attribute_reg(AttributeReg::OverscanColor, 0x04);
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(0, 0x80);
+
+    let (w, h) = (656, 496);
+    let mut buffer = TestBuffer::new(w, h);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    let red = (0xA8, 0x00, 0x00);
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    assert_eq!(buffer.rgb(w, 0, 0), red);
+    assert_eq!(buffer.rgb(w, 15, 9), red);
+    assert_eq!(buffer.rgb(w, 16, 8), red);
+    assert_eq!(buffer.rgb(w, 16, 9), white);
+    assert_eq!(buffer.rgb(w, 17, 9), black);
+    assert_eq!(buffer.rgb(w, 655, 488), black);
+    assert_eq!(buffer.rgb(w, 655, 489), red);
+    assert_eq!(buffer.rgb(w, 655, 495), red);
+    Ok(())
+}
+
+#[test]
+fn test_pel_panning_8_planar() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(0, 0x80);
+    vga.port_in(0x3DA);
+    vga.port_out(0x3C0, 0x33);
+    vga.port_out(0x3C0, 0x08);
+    pass_retrace(&mut vga);
+
+    //a value of 8 does not shift in 8 dot modes
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), (0xFC, 0xFC, 0xFC));
+    assert_eq!(buffer.rgb(w, 1, 0), (0x00, 0x00, 0x00));
+
+    //9 shifts by 1
+    vga.port_out(0x3C0, 0x33);
+    vga.port_out(0x3C0, 0x09);
+    pass_retrace(&mut vga);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), (0x00, 0x00, 0x00));
+    Ok(())
+}
+
+#[test]
+fn test_render_offset_0() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(0, 0x80);
+    vga.port_out(0x3D4, 0x13);
+    vga.port_out(0x3D5, 0x00);
+
+    //every scan line shows the first row
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), (0xFC, 0xFC, 0xFC));
+    assert_eq!(buffer.rgb(w, 0, 479), (0xFC, 0xFC, 0xFC));
+    assert_eq!(buffer.rgb(w, 1, 479), (0x00, 0x00, 0x00));
+    Ok(())
+}
+
+#[test]
+fn test_preset_row_scan_planar() -> Result<(), String> {
+    let mut vga = VGABuilder::new().build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(0, 0x80);
+    vga.write_mem(80 + 2, 0x80);
+    //2 scan lines per row, the first row starts at its second scan line
+    vga.set_crt_data(CRTReg::MaximumScanLine, 0x41);
+    vga.set_crt_data(CRTReg::PresetRowScan, 0x01);
+
+    let (w, h) = (640, 350);
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    let mut buffer = TestBuffer::new(w, h);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), white);
+    assert_eq!(buffer.rgb(w, 0, 1), black);
+    assert_eq!(buffer.rgb(w, 16, 1), white);
+    assert_eq!(buffer.rgb(w, 16, 2), white);
+    assert_eq!(buffer.rgb(w, 16, 3), black);
+
+    //byte panning by 2 bytes, combined with the pixel panning
+    vga.set_crt_data(CRTReg::PresetRowScan, 0x40);
+    vga.set_attribute_reg(AttributeReg::HorizontalPixelPanning, 1);
+    pass_retrace(&mut vga);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), black);
+    assert_eq!(buffer.rgb(w, 0, 2), black);
+    assert_eq!(buffer.rgb(w, 0, 3), black);
+    vga.set_attribute_reg(AttributeReg::HorizontalPixelPanning, 0);
+    pass_retrace(&mut vga);
+    render_planar(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 2), white);
+    assert_eq!(buffer.rgb(w, 0, 3), white);
+    Ok(())
+}
+
+#[test]
+fn test_preset_row_scan_text() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x03).build()?;
+    vga.set_crt_data(CRTReg::CursorStart, 0x20);
+    vga.write_mem(0, 0xDB); //full block
+    vga.write_mem(1, 0x0F);
+    vga.write_mem(2 * 81, 0xDB);
+    vga.write_mem(2 * 81 + 1, 0x0F);
+    //smooth scrolled by 4 scan lines and 1 character
+    vga.set_crt_data(CRTReg::PresetRowScan, 0x24);
+
+    let w = 720;
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    let mut buffer = TestBuffer::new(w, 400);
+    render_text(&vga.vga_emu, 0, 40, 400, &mut buffer, w * 3);
+    //first row shows scan lines 4-15 of the characters 1..
+    assert_eq!(buffer.rgb(w, 0, 0), black);
+    assert_eq!(buffer.rgb(w, 0, 11), black);
+    //second row starts at scan line 0 of the characters 81..
+    assert_eq!(buffer.rgb(w, 0, 12), white);
+    assert_eq!(buffer.rgb(w, 8, 27), white);
+    assert_eq!(buffer.rgb(w, 9, 12), black);
+    assert_eq!(buffer.rgb(w, 0, 28), black);
+    Ok(())
+}
+
+#[test]
+fn test_port_io() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    //index/data pairs with a single word write
+    vga.port_out16(0x3C4, 0x0402);
+    assert_eq!(vga.get_sc_data(SCReg::MapMask), 0x04);
+    assert_eq!(vga.port_in(0x3C5), 0x04);
+    //the high byte of a word write to the last port wraps around to port 0
+    vga.port_out16(0xFFFF, 0x0000);
+    vga.port_out16(0x3CE, 0xAA08);
+    assert_eq!(vga.get_gc_data(GCReg::BitMask), 0xAA);
+    assert_eq!(vga.port_in(0x3CE), 0x08);
+    vga.port_out16(0x3D4, 0x120C);
+    assert_eq!(vga.get_crt_data(CRTReg::StartAdressHigh), 0x12);
+    assert_eq!(vga.port_in(0x3D5), 0x12);
+
+    //CRTC registers 0-7 are write protected (VerticalRetraceEnd bit 7)
+    vga.port_out16(0x3D4, 0x1001);
+    assert_eq!(vga.get_crt_data(CRTReg::HorizontalDisplayEnd), 0x4F);
+    let overflow = vga.get_crt_data(CRTReg::Overflow);
+    vga.port_out16(0x3D4, 0x0007);
+    assert_eq!(vga.get_crt_data(CRTReg::Overflow), overflow & !0x10);
+    let vre = vga.get_crt_data(CRTReg::VerticalRetraceEnd);
+    vga.port_out16(0x3D4, ((vre & 0x7F) as u16) << 8 | 0x11);
+    vga.port_out16(0x3D4, 0x1001);
+    assert_eq!(vga.get_crt_data(CRTReg::HorizontalDisplayEnd), 0x10);
+
+    //attribute controller: index and data through the flip-flop
+    vga.port_in(0x3DA);
+    vga.port_out(0x3C0, 0x01);
+    vga.port_out(0x3C0, 0x3F);
+    assert_eq!(vga.get_attribute_reg(AttributeReg::Palette1), 0x3F);
+    assert_eq!(vga.port_in(0x3C1), 0x3F);
+    vga.port_out(0x3C0, 0x22);
+    assert_eq!(vga.port_in(0x3C0), 0x22);
+    //reading input status 1 resets the flip-flop to index
+    vga.port_in(0x3DA);
+    vga.port_out(0x3C0, 0x23);
+    vga.port_out(0x3C0, 0x15);
+    assert_eq!(vga.get_attribute_reg(AttributeReg::Palette2), 0x02);
+    assert_eq!(vga.get_attribute_reg(AttributeReg::Palette3), 0x15);
+
+    //DAC
+    vga.port_out(0x3C8, 5);
+    vga.port_out(0x3C9, 0x3F);
+    vga.port_out(0x3C9, 0x00);
+    vga.port_out(0x3C9, 0x20);
+    assert_eq!(vga.get_color_palette_256_value(5), 0x3F0020);
+    assert_eq!(vga.port_in(0x3C8), 6);
+    assert_eq!(vga.port_in(0x3C6), 0xFF);
+
+    //misc output, the CRTC moves to 3B4h/3B5h with the I/O address select cleared
+    vga.port_out(0x3C2, 0xE2);
+    assert_eq!(vga.port_in(0x3CC), 0xE2);
+    vga.port_out16(0x3D4, 0x340C);
+    assert_eq!(vga.get_crt_data(CRTReg::StartAdressHigh), 0x12);
+    vga.port_out16(0x3B4, 0x340C);
+    assert_eq!(vga.get_crt_data(CRTReg::StartAdressHigh), 0x34);
+    assert_eq!(vga.port_in(0x3D5), 0xFF);
+    Ok(())
+}
+
+#[test]
+fn test_pel_mask() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x13).build()?;
+    vga.write_mem(0, 0x1F);
+    vga.port_out(0x3C6, 0x0F);
+
+    let (w, h) = (640, 2);
+    let mut buffer = TestBuffer::new(w, h);
+    render_linear(&vga.vga_emu, 0, 40, h, &mut buffer, w * 3);
+    //0x1F & 0x0F = white instead of the gray 0x1F
+    assert_eq!(buffer.rgb(w, 0, 0), (0xFC, 0xFC, 0xFC));
     Ok(())
+}
+
+#[test]
+fn test_raster_timing() -> Result<(), String> {
+    let vga = VGABuilder::new().video_mode(0x12).build()?;
+    let timing = get_raster_timing_regs(&vga.vga_emu.regs);
+    assert_eq!(
+        timing,
+        RasterTiming {
+            dot_clock: 25_175_000,
+            char_dots: 8,
+            h_total: 100,
+            v_total: 525,
+        }
+    );
+    let pos = |line, char_clock| RasterPosition { line, char_clock };
+    assert_eq!(timing.position(0), pos(0, 0));
+    //one scan line = 800 dots = 31.78µs
+    assert_eq!(timing.position(31_700), pos(0, 99));
+    assert_eq!(timing.position(31_800), pos(1, 0));
+    //free running: the next frame starts after 525 lines (16.68ms)
+    assert_eq!(timing.position(16_683_217 + 100), pos(0, 0));
+
+    //mode 03: 28 MHz clock, 9 dot characters, 449 lines
+    let vga = VGABuilder::new().video_mode(0x03).build()?;
+    let timing = get_raster_timing_regs(&vga.vga_emu.regs);
+    assert_eq!(timing.dot_clock, 28_322_000);
+    assert_eq!(timing.char_dots, 9);
+    assert_eq!(timing.v_total, 449);
+    Ok(())
+}
 
-    //TODO Write colors here
-    //check auto-increment
-    //check state register set to 0b11
+#[test]
+fn test_refresh_rate() -> Result<(), String> {
+    //480 lines: 60 Hz
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    assert!((vga.refresh_rate() - 59.94).abs() < 0.01);
+    assert_eq!(vga.frame_time().as_nanos(), 16_683_217);
+    //2 lines vertical retrace
+    assert_eq!(vga.vga_emu.vertical_retrace_time().as_nanos(), 63_554);
+
+    //Mode X 320x240: 527 lines on top of mode 13h
+    let mut vga_x = VGABuilder::new().video_mode(0x13).build()?;
+    vga_x.set_crt_data(CRTReg::VerticalTotal, 0x0D);
+    vga_x.set_crt_data(CRTReg::Overflow, 0x3E);
+    assert!((vga_x.refresh_rate() - 59.71).abs() < 0.01);
+
+    //400 lines: 70 Hz, with the 25 MHz (mode 13h) and 28 MHz (mode 03h) clock
+    for mode in [0x13, 0x03] {
+        let vga = VGABuilder::new().video_mode(mode).build()?;
+        assert!((vga.refresh_rate() - 70.08).abs() < 0.02);
+    }
+
+    //half dot clock
+    vga.set_sc_data(
+        SCReg::ClockingMode,
+        vga.get_sc_data(SCReg::ClockingMode) | 0x08,
+    );
+    assert!((vga.refresh_rate() - 29.97).abs() < 0.01);
+    Ok(())
+}
+
+#[test]
+fn test_display_status() -> Result<(), String> {
+    let vga = VGABuilder::new().video_mode(0x12).build()?;
+    let regs = &vga.vga_emu.regs;
+    let status =
+        |line, char_clock| get_display_status_regs(regs, RasterPosition { line, char_clock });
+    assert_eq!(status(0, 0), 0x00);
+    assert_eq!(status(479, 79), 0x00);
+    //horizontal and vertical non-display
+    assert_eq!(status(0, 80), 0x01);
+    assert_eq!(status(480, 0), 0x01);
+    //vertical retrace from line 490 until the line counter matches the end register (0x0C)
+    assert_eq!(status(489, 0), 0x01);
+    assert_eq!(status(490, 0), 0x09);
+    assert_eq!(status(491, 99), 0x09);
+    assert_eq!(status(492, 0), 0x01);
+    Ok(())
+}
+
+#[test]
+fn test_input_status_1_polling() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x13).build()?;
+    //the clock advances by 5µs per port read, as driven by a CPU emulator
+    let mut nanos = 0;
+    let mut poll = |vga: &mut VGA| {
+        nanos += 5_000;
+        assert!(nanos < 2 * 14_268_000, "no vertical retrace in two frames");
+        vga.set_raster_time(nanos);
+        vga.port_in(0x3DA)
+    };
+    //wait for the start of the vertical retrace and its end, as programs do
+    while poll(&mut vga) & 0x08 == 0 {}
+    assert_eq!(vga.get_general_reg(GeneralReg::InputStatus1) & 0x01, 0x01);
+    assert_eq!(vga.vga_emu.raster_position().line, 412);
+    while poll(&mut vga) & 0x08 != 0 {}
+    assert_eq!(vga.vga_emu.raster_position().line, 414);
+    Ok(())
+}
+
+#[test]
+fn test_raster_effects() -> Result<(), String> {
+    let mut vga = VGABuilder::new()
+        .video_mode(0x13)
+        .raster_effects()
+        .build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    for i in 0..320 * 200 {
+        vga.write_mem(i, 0x01);
+    }
+    //one scan line = 800 dots at 25.175 MHz
+    let line = |l: u64| l * 31_778 + 1_000;
+    let set_color = |vga: &mut VGA, r, g, b| {
+        vga.port_out(0x3C8, 0x01);
+        vga.port_out(0x3C9, r);
+        vga.port_out(0x3C9, g);
+        vga.port_out(0x3C9, b);
+    };
+    vga.set_raster_time(line(10));
+    set_color(&mut vga, 0x2A, 0x00, 0x00);
+    vga.set_raster_time(line(100));
+    set_color(&mut vga, 0x3F, 0x3F, 0x3F);
+    vga.set_raster_time(line(200));
+    set_color(&mut vga, 0x2A, 0x00, 0x00);
+
+    let (w, h) = (640, 400);
+    let mut buffer = TestBuffer::new(w, h);
+    let blue = (0x00, 0x00, 0xA8);
+    let red = (0xA8, 0x00, 0x00);
+    let white = (0xFC, 0xFC, 0xFC);
+    //no frame completed yet: the current state
+    vga.set_raster_time(line(300));
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), red);
+    assert_eq!(buffer.rgb(w, 0, 150), red);
+
+    //the completed frame is rendered with the writes at their scan lines
+    let frame = 14_268_123;
+    vga.set_raster_time(frame + line(50));
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 9), blue);
+    assert_eq!(buffer.rgb(w, 0, 10), red);
+    assert_eq!(buffer.rgb(w, 639, 99), red);
+    assert_eq!(buffer.rgb(w, 0, 100), white);
+    assert_eq!(buffer.rgb(w, 0, 199), white);
+    assert_eq!(buffer.rgb(w, 0, 200), red);
+    assert_eq!(buffer.rgb(w, 0, 399), red);
+    //the registers keep the latest state
+    assert_eq!(vga.get_color_palette_256_value(1), 0x2A0000);
+
+    //a frame without writes
+    vga.set_raster_time(3 * frame + line(50));
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), red);
+    assert_eq!(buffer.rgb(w, 0, 150), red);
+    Ok(())
+}
+
+#[test]
+fn test_raster_effects_timing_change() -> Result<(), String> {
+    let mut vga = VGABuilder::new()
+        .video_mode(0x13)
+        .raster_effects()
+        .build()?;
+    let completed = |vga: &mut VGA| vga.vga_emu.take_raster_frame().map(|f| f.frame);
+    let frame_70 = 14_268_123;
+    let frame_60 = 16_683_217;
+    let t = 100 * frame_70 + 50 * 31_778 + 1_000;
+    vga.set_raster_time(t);
+    assert_eq!(completed(&mut vga), Some(99));
+
+    //switch from 449 to 525 lines (70 Hz to 60 Hz) in the middle of frame 100
+    vga.set_crt_data(CRTReg::VerticalTotal, 0x0B);
+    vga.set_crt_data(CRTReg::Overflow, 0x3E);
+    assert_eq!(vga.vga_emu.raster_position().line, 50);
+    vga.set_raster_time(t + frame_60 - 60 * 31_778);
+    assert_eq!(completed(&mut vga), Some(99));
+    vga.set_raster_time(t + frame_60);
+    assert_eq!(completed(&mut vga), Some(100));
+    vga.set_raster_time(t + 3 * frame_60);
+    assert_eq!(completed(&mut vga), Some(102));
+    Ok(())
+}
+
+#[test]
+fn test_raster_effects_stalled_clock() -> Result<(), String> {
+    let mut vga = VGABuilder::new()
+        .video_mode(0x13)
+        .raster_effects()
+        .build()?;
+    //a guest polling and writing registers while the host does not advance the clock
+    vga.set_raster_time(10 * 31_778 + 1_000);
+    vga.port_in(0x3DA);
+    for i in 0..10_000u32 {
+        vga.port_out(0x3C8, 0x01);
+        vga.port_out(0x3C9, (i & 0x3F) as u8);
+        vga.port_out(0x3C9, 0x00);
+        vga.port_out(0x3C9, 0x00);
+        vga.port_out(0x3C0, 0x33);
+        vga.port_out(0x3C0, (i & 0x07) as u8);
+    }
+    vga.set_raster_time(14_268_123 + 1_000);
+    let frame = vga
+        .vga_emu
+        .take_raster_frame()
+        .ok_or("no completed frame")?;
+    assert_eq!(
+        frame.writes,
+        vec![
+            (10, RegWrite::Dac(0x01, 0x0F0000)),
+            (10, RegWrite::Attribute(0x13, 0x07))
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_raster_effects_attribute() -> Result<(), String> {
+    let mut vga = VGABuilder::new()
+        .video_mode(0x12)
+        .raster_effects()
+        .build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    for y in 0..480 {
+        vga.write_mem(y * 80, 0x80);
+    }
+    //palette register 15 (with the palette address source bit) to red at line 240
+    vga.set_raster_time(240 * 31_778 + 1_000);
+    vga.port_in(0x3DA);
+    vga.port_out(0x3C0, 0x2F);
+    vga.port_out(0x3C0, 0x04);
+
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    //rendered in the next frame
+    vga.set_raster_time(16_683_217 + 1_000);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    let white = (0xFC, 0xFC, 0xFC);
+    let red = (0xA8, 0x00, 0x00);
+    assert_eq!(buffer.rgb(w, 0, 239), white);
+    assert_eq!(buffer.rgb(w, 0, 240), red);
+    assert_eq!(buffer.rgb(w, 0, 479), red);
+    Ok(())
+}
+
+#[test]
+fn test_raster_effects_panning() -> Result<(), String> {
+    let mut vga = VGABuilder::new()
+        .video_mode(0x12)
+        .raster_effects()
+        .build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    for y in 0..480 {
+        vga.write_mem(y * 80, 0x80);
+    }
+    vga.set_raster_time(240 * 31_778 + 1_000);
+    vga.port_in(0x3DA);
+    vga.port_out(0x3C0, 0x33);
+    vga.port_out(0x3C0, 0x01);
+
+    let (w, h) = (640, 480);
+    let mut buffer = TestBuffer::new(w, h);
+    //rendered in the next frame
+    vga.set_raster_time(16_683_217 + 1_000);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    assert_eq!(buffer.rgb(w, 0, 239), white);
+    assert_eq!(buffer.rgb(w, 0, 240), black);
+    assert_eq!(buffer.rgb(w, 0, 479), black);
+    Ok(())
+}
+
+#[test]
+fn test_display_latch() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    vga.set_sc_data(SCReg::MapMask, 0x0F);
+    vga.write_mem(0, 0x80);
+    vga.write_mem(80, 0x40);
+    let (w, h) = (640, 480);
+    let white = (0xFC, 0xFC, 0xFC);
+    let black = (0x00, 0x00, 0x00);
+    let mut buffer = TestBuffer::new(w, h);
+
+    //written in the active display: not taken over in the current frame
+    vga.set_raster_time(100 * 31_778);
+    vga.set_crt_data(CRTReg::StartAdressLow, 80);
+    vga.set_attribute_reg(AttributeReg::HorizontalPixelPanning, 1);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), white);
+    assert_eq!(vga.vga_emu.mem_offset(), 0);
+
+    //the start address is latched at the vertical retrace start (line 490),
+    //the pel panning at its end (line 492)
+    vga.set_raster_time(491 * 31_778);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(vga.vga_emu.mem_offset(), 80);
+    assert_eq!(vga.vga_emu.pel_panning(), 0);
+    assert_eq!(buffer.rgb(w, 0, 0), black);
+    assert_eq!(buffer.rgb(w, 1, 0), white);
+    vga.set_raster_time(493 * 31_778);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(vga.vga_emu.pel_panning(), 1);
+    assert_eq!(buffer.rgb(w, 0, 0), white);
+
+    //the override bypasses the latch
+    vga.vga_emu.start_addr_override = Some(0);
+    render(&mut vga.vga_emu, h, &mut buffer, w * 3);
+    assert_eq!(buffer.rgb(w, 0, 0), black);
+    assert_eq!(buffer.rgb(w, 0, 1), white);
+
+    //a timing change does not latch before the next retrace
+    vga.vga_emu.start_addr_override = None;
+    let t = 10 * 16_683_217 + 100 * 31_778;
+    vga.set_raster_time(t);
+    vga.set_crt_data(CRTReg::StartAdressLow, 160);
+    vga.set_general_reg(GeneralReg::MiscOutput, 0xE7); //28 MHz clock
+    vga.vga_emu.latch_display();
+    assert_eq!(vga.vga_emu.mem_offset(), 80);
+    //one scan line = 800 dots at 28.322 MHz
+    vga.set_raster_time(t + 391 * 28_246);
+    vga.vga_emu.latch_display();
+    assert_eq!(vga.vga_emu.mem_offset(), 160);
+    Ok(())
+}
+
+#[test]
+fn test_vertical_interrupt() -> Result<(), String> {
+    let mut vga = VGABuilder::new().video_mode(0x12).build()?;
+    let count = Arc::new(AtomicU32::new(0));
+    let handler_count = count.clone();
+    vga.set_vertical_interrupt_handler(move || {
+        handler_count.fetch_add(1, Ordering::Relaxed);
+    });
+    let frame = 16_683_217;
+    let retrace = 491 * 31_778;
+
+    //held cleared by the BIOS mode setup (bit 4 = 0)
+    vga.set_raster_time(retrace);
+    assert_eq!(vga.port_in(0x3C2) & 0x80, 0);
+    assert_eq!(count.load(Ordering::Relaxed), 0);
+
+    //arm the interrupt, it is raised at the next retrace start
+    vga.port_out16(0x3D4, 0x9C11);
+    vga.set_raster_time(frame + 100 * 31_778);
+    assert!(!vga.vertical_interrupt_pending());
+    vga.set_raster_time(frame + retrace);
+    assert!(vga.vertical_interrupt_pending());
+    assert_eq!(vga.port_in(0x3C2) & 0x80, 0x80);
+    assert_eq!(vga.get_general_reg(GeneralReg::InputStatus0) & 0x80, 0x80);
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+
+    //not raised again until it was cleared
+    vga.set_raster_time(2 * frame + retrace);
+    vga.poll_vertical_interrupt();
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+    vga.port_out16(0x3D4, 0x8C11);
+    assert_eq!(vga.port_in(0x3C2) & 0x80, 0);
+    vga.port_out16(0x3D4, 0x9C11);
+    vga.set_raster_time(3 * frame + retrace);
+    vga.poll_vertical_interrupt();
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+
+    //disabled (bit 5): pending, but no interrupt request
+    vga.port_out16(0x3D4, 0x8C11);
+    vga.port_out16(0x3D4, 0xBC11);
+    vga.set_raster_time(4 * frame + retrace);
+    vga.poll_vertical_interrupt();
+    assert!(vga.vertical_interrupt_pending());
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+
+    //a timing change raises no interrupt before the next retrace
+    let t = 10 * frame + 100 * 31_778;
+    vga.set_raster_time(t);
+    vga.port_out16(0x3D4, 0x8C11);
+    vga.port_out16(0x3D4, 0x9C11);
+    vga.port_out16(0x3D4, 0x0006); //vertical total: 514 lines
+    vga.port_out(0x3C2, 0xE7); //28 MHz clock
+    assert!(!vga.vertical_interrupt_pending());
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+    //one scan line = 800 dots at 28.322 MHz, the beam continues at line 100
+    vga.set_raster_time(t + 389 * 28_246);
+    vga.poll_vertical_interrupt();
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+    vga.set_raster_time(t + 391 * 28_246);
+    vga.poll_vertical_interrupt();
+    assert_eq!(count.load(Ordering::Relaxed), 3);
+
+    //the emulator can be moved to another thread with a handler registered
+    fn is_send<T: Send>() {}
+    is_send::<VGAEmu>();
+    Ok(())
 }
@@ -0,0 +1,151 @@
+// Port I/O access to the VGA registers (3B4h-3BAh, 3C0h-3DFh)
+
+use crate::raster::RegWrite;
+use crate::{CRTReg, ColorReg, GeneralReg, VGAEmu};
+
+pub const ATTRIBUTE_ADDRESS: u16 = 0x3C0;
+pub const ATTRIBUTE_DATA_READ: u16 = 0x3C1;
+pub const MISC_OUTPUT_WRITE: u16 = 0x3C2;
+pub const INPUT_STATUS_0: u16 = 0x3C2;
+pub const SC_INDEX: u16 = 0x3C4;
+pub const SC_DATA: u16 = 0x3C5;
+pub const PEL_MASK: u16 = 0x3C6;
+pub const DAC_READ_INDEX: u16 = 0x3C7;
+pub const DAC_STATE: u16 = 0x3C7;
+pub const DAC_WRITE_INDEX: u16 = 0x3C8;
+pub const DAC_DATA: u16 = 0x3C9;
+pub const FEATURE_CONTROL_READ: u16 = 0x3CA;
+pub const MISC_OUTPUT_READ: u16 = 0x3CC;
+pub const GC_INDEX: u16 = 0x3CE;
+pub const GC_DATA: u16 = 0x3CF;
+pub const CRTC_INDEX_MONO: u16 = 0x3B4;
+pub const CRTC_DATA_MONO: u16 = 0x3B5;
+pub const INPUT_STATUS_1_MONO: u16 = 0x3BA;
+pub const CRTC_INDEX: u16 = 0x3D4;
+pub const CRTC_DATA: u16 = 0x3D5;
+pub const INPUT_STATUS_1: u16 = 0x3DA;
+
+/// Index registers and the attribute controller flip-flop
+pub struct PortState {
+    sc_index: u8,
+    gc_index: u8,
+    crt_index: u8,
+    attribute_index: u8,
+    /// false = the next write to 3C0h is an index, true = data
+    attribute_data: bool,
+}
+
+impl PortState {
+    pub(crate) fn new() -> PortState {
+        PortState {
+            sc_index: 0,
+            gc_index: 0,
+            crt_index: 0,
+            //palette address source set: video enabled
+            attribute_index: 0x20,
+            attribute_data: false,
+        }
+    }
+}
+
+impl VGAEmu {
+    /// Byte write to the I/O port `port`. Writes to unknown ports are ignored.
+    pub fn port_out(&mut self, port: u16, v: u8) {
+        self.poll_vertical_interrupt();
+        match self.crtc_port(port) {
+            ATTRIBUTE_ADDRESS => {
+                if self.port.attribute_data {
+                    let ix = self.port.attribute_index & 0x1F;
+                    self.write_reg(RegWrite::Attribute(ix, v));
+                } else {
+                    self.port.attribute_index = v & 0x3F;
+                }
+                self.port.attribute_data = !self.port.attribute_data;
+            }
+            MISC_OUTPUT_WRITE => self.write_reg(RegWrite::General(GeneralReg::MiscOutput as u8, v)),
+            SC_INDEX => self.port.sc_index = v,
+            SC_DATA => self.write_reg(RegWrite::Sc(self.port.sc_index, v)),
+            PEL_MASK => self.set_color_reg(ColorReg::PelMask, v),
+            DAC_READ_INDEX => self.set_color_reg(ColorReg::AddressReadMode, v),
+            DAC_WRITE_INDEX => self.set_color_reg(ColorReg::AddressWriteMode, v),
+            DAC_DATA => self.set_color_reg(ColorReg::Data, v),
+            GC_INDEX => self.port.gc_index = v,
+            GC_DATA => self.write_reg(RegWrite::Gc(self.port.gc_index, v)),
+            CRTC_INDEX => self.port.crt_index = v,
+            CRTC_DATA => self.set_crt_port(v),
+            INPUT_STATUS_1 => self.regs.set_general_reg(GeneralReg::FeatureContorl, v),
+            _ => {}
+        }
+    }
+
+    /// Word write to the I/O port `port`: the low byte goes to `port`, the
+    /// high byte to `port + 1` (index and data with a single `out dx, ax`)
+    pub fn port_out16(&mut self, port: u16, v: u16) {
+        self.port_out(port, v as u8);
+        self.port_out(port.wrapping_add(1), (v >> 8) as u8);
+    }
+
+    /// Byte read from the I/O port `port`. Unknown ports read as 0xFF.
+    pub fn port_in(&mut self, port: u16) -> u8 {
+        self.poll_vertical_interrupt();
+        match self.crtc_port(port) {
+            ATTRIBUTE_ADDRESS => self.port.attribute_index,
+            ATTRIBUTE_DATA_READ => {
+                let ix = (self.port.attribute_index & 0x1F) as usize;
+                self.regs.attribute_reg.get(ix).copied().unwrap_or(0xFF)
+            }
+            INPUT_STATUS_0 => self.input_status_0(),
+            SC_INDEX => self.port.sc_index,
+            SC_DATA => get_indexed(&self.regs.sc_reg, self.port.sc_index),
+            PEL_MASK => self.get_color_reg(ColorReg::PelMask),
+            DAC_STATE => self.get_color_reg(ColorReg::State),
+            DAC_WRITE_INDEX => self.get_color_reg(ColorReg::AddressWriteMode),
+            DAC_DATA => self.get_color_reg(ColorReg::Data),
+            FEATURE_CONTROL_READ => self.regs.get_general_reg(GeneralReg::FeatureContorl),
+            MISC_OUTPUT_READ => self.regs.get_general_reg(GeneralReg::MiscOutput),
+            GC_INDEX => self.port.gc_index,
+            GC_DATA => get_indexed(&self.regs.gc_reg, self.port.gc_index),
+            CRTC_INDEX => self.port.crt_index,
+            CRTC_DATA => get_indexed(&self.regs.crt_reg, self.port.crt_index),
+            INPUT_STATUS_1 => {
+                //reading the input status resets the attribute controller flip-flop
+                self.port.attribute_data = false;
+                self.input_status_1()
+            }
+            _ => 0xFF,
+        }
+    }
+
+    /// Maps the CRTC and input status 1 ports to their color addresses (3Dxh).
+    /// The I/O address select (GeneralReg::MiscOutput bit 0) decides whether
+    /// the CRTC is decoded at 3Bxh (monochrome) or 3Dxh (color).
+    fn crtc_port(&self, port: u16) -> u16 {
+        let color = self.regs.get_general_reg(GeneralReg::MiscOutput) & 0x01 != 0;
+        match port {
+            CRTC_INDEX | CRTC_DATA | INPUT_STATUS_1 if !color => 0,
+            CRTC_INDEX_MONO | CRTC_DATA_MONO | INPUT_STATUS_1_MONO if color => 0,
+            CRTC_INDEX_MONO | CRTC_DATA_MONO | INPUT_STATUS_1_MONO => port + 0x20,
+            _ => port,
+        }
+    }
+
+    /// The CRTC registers 0-7 are write protected if CRTReg::VerticalRetraceEnd bit 7
+    /// is set. Only the line compare bit 8 (bit 4) in CRTReg::Overflow stays writeable.
+    fn set_crt_port(&mut self, v: u8) {
+        let ix = self.port.crt_index;
+        let protect = self.regs.get_crt_data(CRTReg::VerticalRetraceEnd) & 0x80 != 0;
+        if protect && ix < CRTReg::Overflow as u8 {
+            return;
+        }
+        if protect && ix == CRTReg::Overflow as u8 {
+            let overflow = self.regs.get_crt_data(CRTReg::Overflow);
+            self.write_reg(RegWrite::Crt(ix, (overflow & !0x10) | (v & 0x10)));
+            return;
+        }
+        self.write_reg(RegWrite::Crt(ix, v));
+    }
+}
+
+fn get_indexed(regs: &[u8], ix: u8) -> u8 {
+    regs.get(ix as usize).copied().unwrap_or(0xFF)
+}
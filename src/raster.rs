@@ -0,0 +1,215 @@
+// Timestamped register and DAC writes for the scanline renderer (raster effects)
+// and the display registers latched at the vertical retrace
+
+use crate::clock::RasterCounters;
+use crate::{AttributeReg, CRTReg, ColorReg, VGAEmu, VGARegs};
+
+/// A write to a register (index, value) or a DAC entry that affects the display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegWrite {
+    Sc(u8, u8),
+    Gc(u8, u8),
+    Crt(u8, u8),
+    Attribute(u8, u8),
+    General(u8, u8),
+    /// DAC entry and its color (6 bit RGB) after the write
+    Dac(u8, u32),
+    PelMask(u8),
+}
+
+impl RegWrite {
+    /// Writes to the registers the CRTC only takes over at the vertical retrace
+    fn is_latched(&self) -> bool {
+        match *self {
+            RegWrite::Crt(ix, _) => {
+                ix == CRTReg::StartAdressHigh as u8 || ix == CRTReg::StartAdressLow as u8
+            }
+            RegWrite::Attribute(ix, _) => ix == AttributeReg::HorizontalPixelPanning as u8,
+            _ => false,
+        }
+    }
+
+    /// Whether both writes change the same register or DAC entry
+    fn same_target(&self, other: &RegWrite) -> bool {
+        match (*self, *other) {
+            (RegWrite::Sc(a, _), RegWrite::Sc(b, _))
+            | (RegWrite::Gc(a, _), RegWrite::Gc(b, _))
+            | (RegWrite::Crt(a, _), RegWrite::Crt(b, _))
+            | (RegWrite::Attribute(a, _), RegWrite::Attribute(b, _))
+            | (RegWrite::General(a, _), RegWrite::General(b, _))
+            | (RegWrite::Dac(a, _), RegWrite::Dac(b, _)) => a == b,
+            (RegWrite::PelMask(_), RegWrite::PelMask(_)) => true,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn apply(self, regs: &mut VGARegs, palette: &mut [u32; 256]) {
+        match self {
+            RegWrite::Sc(ix, v) => set_indexed(&mut regs.sc_reg, ix, v),
+            RegWrite::Gc(ix, v) => set_indexed(&mut regs.gc_reg, ix, v),
+            RegWrite::Crt(ix, v) => set_indexed(&mut regs.crt_reg, ix, v),
+            RegWrite::Attribute(ix, v) => set_indexed(&mut regs.attribute_reg, ix, v),
+            RegWrite::General(ix, v) => set_indexed(&mut regs.general_reg, ix, v),
+            RegWrite::Dac(ix, color) => palette[ix as usize] = color,
+            RegWrite::PelMask(v) => regs.color_reg[ColorReg::PelMask as usize] = v,
+        }
+    }
+}
+
+/// The register state at the start of a frame and the writes during the frame
+/// (scan line, write)
+#[derive(Clone)]
+pub(crate) struct RasterFrame {
+    pub frame: u64,
+    pub regs: VGARegs,
+    pub palette: [u32; 256],
+    pub writes: Vec<(usize, RegWrite)>,
+}
+
+impl RasterFrame {
+    /// Logs the write. Only the last write to a register or DAC entry within a
+    /// scan line is kept, so the log stays bounded while the clock stands still.
+    fn push(&mut self, line: usize, write: RegWrite) {
+        let same_line = self.writes.iter_mut().rev().take_while(|(l, _)| *l == line);
+        for (_, logged) in same_line {
+            if logged.same_target(&write) {
+                *logged = write;
+                return;
+            }
+        }
+        self.writes.push((line, write));
+    }
+}
+
+/// The frame in progress and the last completed frame, the one that is rendered
+pub(crate) struct RasterLog {
+    current: RasterFrame,
+    completed: Option<RasterFrame>,
+}
+
+/// The start address (latched at the start of the vertical retrace) and the
+/// pel panning (latched at its end) the display is scanned with
+pub(crate) struct DisplayLatch {
+    start_addr: u16,
+    pel_panning: u8,
+    /// retrace starts and ends already latched
+    starts: u64,
+    ends: u64,
+}
+
+impl DisplayLatch {
+    pub(crate) fn new(regs: &VGARegs) -> DisplayLatch {
+        DisplayLatch {
+            start_addr: start_addr_regs(regs),
+            pel_panning: regs.get_attribute_reg(AttributeReg::HorizontalPixelPanning),
+            starts: 0,
+            ends: 0,
+        }
+    }
+}
+
+fn start_addr_regs(regs: &VGARegs) -> u16 {
+    (regs.get_crt_data(CRTReg::StartAdressHigh) as u16) << 8
+        | regs.get_crt_data(CRTReg::StartAdressLow) as u16
+}
+
+impl VGAEmu {
+    /// Takes over the start address and pel panning if the beam passed the start
+    /// or end of the vertical retrace since the last call. Must be called before
+    /// the registers change, the latches are updated lazily.
+    pub(crate) fn latch_display(&mut self) {
+        let RasterCounters { starts, ends, .. } = self.raster_counters();
+        if starts != self.latch.starts {
+            self.latch.start_addr = start_addr_regs(&self.regs);
+            self.latch.starts = starts;
+        }
+        if ends != self.latch.ends {
+            self.latch.pel_panning = self
+                .regs
+                .get_attribute_reg(AttributeReg::HorizontalPixelPanning);
+            self.latch.ends = ends;
+        }
+    }
+
+    /// The display start address as latched at the last vertical retrace start
+    pub(crate) fn latched_start_addr(&self) -> usize {
+        self.latch.start_addr as usize
+    }
+
+    /// The horizontal pixel panning as latched at the last vertical retrace end.
+    /// With raster effects enabled the renderer applies panning writes per scan line instead.
+    pub fn pel_panning(&self) -> u8 {
+        self.latch.pel_panning
+    }
+
+    pub(crate) fn new_raster_log(&self) -> RasterLog {
+        RasterLog {
+            current: self.new_raster_frame(0),
+            completed: None,
+        }
+    }
+
+    fn new_raster_frame(&self, frame: u64) -> RasterFrame {
+        RasterFrame {
+            frame,
+            regs: self.regs.clone(),
+            palette: self.palette_256,
+            writes: Vec::new(),
+        }
+    }
+
+    /// Applies the write to the registers and records it with the current
+    /// raster position if raster effects are enabled
+    pub(crate) fn write_reg(&mut self, write: RegWrite) {
+        if write.is_latched() {
+            self.latch_display();
+        }
+        if let RegWrite::Crt(ix, v) = write
+            && ix == CRTReg::VerticalRetraceEnd as u8
+        {
+            self.set_vertical_retrace_end(v);
+        }
+        self.sync_raster_frame();
+        write.apply(&mut self.regs, &mut self.palette_256);
+        self.base.rebase(&self.regs, self.clock.elapsed_nanos());
+        if self.raster.is_some() {
+            let line = self.raster_position().line as usize;
+            if let Some(log) = self.raster.as_mut() {
+                log.current.push(line, write);
+            }
+        }
+    }
+
+    /// Completes the logged frame if the beam started a new frame. Must be
+    /// called before the registers change: the current state becomes the
+    /// state at the start of the new frame.
+    fn sync_raster_frame(&mut self) {
+        let Some(current_frame) = self.raster.as_ref().map(|log| log.current.frame) else {
+            return;
+        };
+        let frame = self.raster_counters().frame;
+        if frame <= current_frame {
+            return;
+        }
+        let next = self.new_raster_frame(frame);
+        //without writes in the previous frame the state stayed the same all over it
+        let idle = self.new_raster_frame(frame - 1);
+        if let Some(log) = self.raster.as_mut() {
+            let last = std::mem::replace(&mut log.current, next);
+            log.completed = Some(if last.frame + 1 == frame { last } else { idle });
+        }
+    }
+
+    /// The last completed frame to render, with the writes at the scan lines
+    /// they occurred in. None if raster effects are disabled or no frame completed yet.
+    pub(crate) fn take_raster_frame(&mut self) -> Option<RasterFrame> {
+        self.sync_raster_frame();
+        self.raster.as_ref()?.completed.clone()
+    }
+}
+
+pub(crate) fn set_indexed(regs: &mut [u8], ix: u8, v: u8) {
+    if let Some(reg) = regs.get_mut(ix as usize) {
+        *reg = v;
+    }
+}
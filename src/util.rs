@@ -18,7 +18,20 @@ const CLEAR_VR_MASK: u8 = 0b11110111;
 /// width in pixel
 
 pub fn get_width_regs(regs: &VGARegs) -> u32 {
-    (regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as u32 + 1) * 8
+    let clocking_mode = regs.get_sc_data(SCReg::ClockingMode);
+    let dot_repeat = if clocking_mode & 0x08 != 0 { 2 } else { 1 };
+    (regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as u32 + 1)
+        * get_char_width_regs(regs)
+        * dot_repeat
+}
+
+/// width of a character clock in dots (8 or 9)
+pub fn get_char_width_regs(regs: &VGARegs) -> u32 {
+    if regs.get_sc_data(SCReg::ClockingMode) & 0x01 != 0 {
+        8
+    } else {
+        9
+    }
 }
 
 pub fn get_width(vga: &VGAEmu) -> u32 {
@@ -33,6 +46,19 @@ pub fn get_height(vga: &VGAEmu) -> u32 {
     get_height_regs(&vga.regs)
 }
 
+/// Size of the rendered frame in pixel (width, height): the active display area
+/// plus the border if overscan rendering is enabled
+pub fn get_frame_size(vga: &VGAEmu) -> (u32, u32) {
+    let mut width = get_width(vga);
+    let mut height = get_height(vga);
+    if vga.overscan {
+        let border = get_overscan_regs(&vga.regs);
+        width += border.left + border.right;
+        height += border.top + border.bottom;
+    }
+    (width, height)
+}
+
 /// Constructs the Vertical Display End from the register + offset register
 fn get_vertical_display_end(regs: &VGARegs) -> u32 {
     let vde_lower = regs.get_crt_data(CRTReg::VerticalDisplayEnd);
@@ -44,7 +70,215 @@ fn get_vertical_display_end(regs: &VGARegs) -> u32 {
     vde | ((vde_upper as u32) << 8)
 }
 
+/// Size of the border (overscan) around the active display area in pixel
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Overscan {
+    pub left: u32,
+    pub right: u32,
+    pub top: u32,
+    pub bottom: u32,
+}
+
+/// The border is the part of the frame that is neither active display nor blanked.
+/// Computed from the total, display end and blanking registers.
+pub fn get_overscan_regs(regs: &VGARegs) -> Overscan {
+    let overflow = regs.get_crt_data(CRTReg::Overflow) as u32;
+    let msl = regs.get_crt_data(CRTReg::MaximumScanLine) as u32;
+
+    //horizontal, in character clocks
+    let h_total = regs.get_crt_data(CRTReg::HorizontalTotal) as u32 + 5;
+    let h_display = regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as u32 + 1;
+    let h_blank_start = (regs.get_crt_data(CRTReg::StartHorizontalBlanking) as u32).min(h_total);
+    //6 bit value: bits 0-4 in the end horizontal blanking register, bit 5 in the end horizontal retrace register
+    let h_blank_end_bits = (regs.get_crt_data(CRTReg::EndHorizontalBlanking) & 0x1F) as u32
+        | ((regs.get_crt_data(CRTReg::EndHorizontalRetrace) & 0x80) >> 2) as u32;
+    let h_blank_end = blanking_end(h_blank_start, h_blank_end_bits, 0x3F);
+
+    //vertical, in scan lines
+    let v_total = get_vertical_total_regs(regs);
+    let v_display = get_height_regs(regs);
+    let v_blank_start = (regs.get_crt_data(CRTReg::StartVerticalBlanking) as u32
+        | (overflow & 0x08) << 5
+        | (msl & 0x20) << 4)
+        .min(v_total);
+    let v_blank_end_bits = regs.get_crt_data(CRTReg::EndVerticalBlanking) as u32;
+    let v_blank_end = blanking_end(v_blank_start, v_blank_end_bits, 0xFF);
+
+    let clocking_mode = regs.get_sc_data(SCReg::ClockingMode);
+    let dot_repeat = if clocking_mode & 0x08 != 0 { 2 } else { 1 };
+    let char_width = get_char_width_regs(regs) * dot_repeat;
+    Overscan {
+        left: h_total.saturating_sub(h_blank_end) * char_width,
+        right: h_blank_start.saturating_sub(h_display) * char_width,
+        top: v_total.saturating_sub(v_blank_end),
+        bottom: v_blank_start.saturating_sub(v_display),
+    }
+}
+
+/// Scan lines per frame: the vertical total register + overflow bits 8/9, +2
+pub fn get_vertical_total_regs(regs: &VGARegs) -> u32 {
+    let overflow = regs.get_crt_data(CRTReg::Overflow) as u32;
+    (regs.get_crt_data(CRTReg::VerticalTotal) as u32
+        | (overflow & 0x01) << 8
+        | (overflow & 0x20) << 4)
+        + 2
+}
+
+/// The CRTC timing of a frame
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RasterTiming {
+    /// dot clock in Hz
+    pub dot_clock: u32,
+    /// dots per character clock (dot clock / 2 included)
+    pub char_dots: u32,
+    /// character clocks per scan line
+    pub h_total: u32,
+    /// scan lines per frame
+    pub v_total: u32,
+}
+
+/// Position of the raster beam in the frame
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RasterPosition {
+    pub line: u32,
+    pub char_clock: u32,
+}
+
+impl RasterTiming {
+    /// The raster position `elapsed_nanos` after the start of a frame (free running)
+    pub fn position(&self, elapsed_nanos: u64) -> RasterPosition {
+        let line_dots = (self.h_total * self.char_dots) as u128;
+        let frame_dots = line_dots * self.v_total as u128;
+        let dots = elapsed_nanos as u128 * self.dot_clock as u128 / 1_000_000_000 % frame_dots;
+        RasterPosition {
+            line: (dots / line_dots) as u32,
+            char_clock: ((dots % line_dots) / self.char_dots as u128) as u32,
+        }
+    }
+
+    /// The number of frames completed `elapsed_nanos` after the start of the first frame
+    pub fn frame(&self, elapsed_nanos: u64) -> u64 {
+        let frame_dots = (self.h_total * self.char_dots) as u128 * self.v_total as u128;
+        (elapsed_nanos as u128 * self.dot_clock as u128 / 1_000_000_000 / frame_dots) as u64
+    }
+
+    /// Duration of a scan line in nanoseconds
+    pub fn line_nanos(&self) -> u64 {
+        (self.h_total * self.char_dots) as u64 * 1_000_000_000 / self.dot_clock as u64
+    }
+
+    /// Duration of a frame in nanoseconds
+    pub fn frame_nanos(&self) -> u64 {
+        let frame_dots = (self.h_total * self.char_dots) as u64 * self.v_total as u64;
+        frame_dots * 1_000_000_000 / self.dot_clock as u64
+    }
+
+    /// Frames per second
+    pub fn refresh_rate(&self) -> f64 {
+        self.dot_clock as f64 / (self.h_total * self.char_dots * self.v_total) as f64
+    }
+
+    /// How often the beam reached the scan line `line` within `elapsed_nanos`
+    /// after the start of the first frame
+    pub fn passes(&self, elapsed_nanos: u64, line: u32) -> u64 {
+        let reached = self.position(elapsed_nanos).line >= line % self.v_total;
+        self.frame(elapsed_nanos) + reached as u64
+    }
+}
+
+/// The timing from the clock select (GeneralReg::MiscOutput bits 2-3), the clocking
+/// mode and the horizontal/vertical total registers
+pub fn get_raster_timing_regs(regs: &VGARegs) -> RasterTiming {
+    let dot_clock = match (regs.get_general_reg(GeneralReg::MiscOutput) >> 2) & 0x03 {
+        0x01 => 28_322_000,
+        _ => 25_175_000,
+    };
+    let dot_repeat = if regs.get_sc_data(SCReg::ClockingMode) & 0x08 != 0 {
+        2
+    } else {
+        1
+    };
+    RasterTiming {
+        dot_clock,
+        char_dots: get_char_width_regs(regs) * dot_repeat,
+        h_total: regs.get_crt_data(CRTReg::HorizontalTotal) as u32 + 5,
+        v_total: get_vertical_total_regs(regs),
+    }
+}
+
+/// The GeneralReg::InputStatus1 bits for the raster position: display disabled
+/// (bit 0, outside of the active display area) and vertical retrace (bit 3)
+pub fn get_display_status_regs(regs: &VGARegs, pos: RasterPosition) -> u8 {
+    let mut status = 0;
+    let h_display = regs.get_crt_data(CRTReg::HorizontalDisplayEnd) as u32 + 1;
+    if pos.char_clock >= h_display || pos.line >= get_height_regs(regs) {
+        status |= !CLEAR_DE_MASK;
+    }
+    let (vr_start, vr_end) = get_vertical_retrace_regs(regs);
+    if pos.line >= vr_start && pos.line < vr_end {
+        status |= !CLEAR_VR_MASK;
+    }
+    status
+}
+
+/// The vertical retrace scan lines (start, end): from the vertical retrace start
+/// register + overflow bits 8/9 to the first line after it whose lower 4 bits
+/// match the vertical retrace end register
+pub fn get_vertical_retrace_regs(regs: &VGARegs) -> (u32, u32) {
+    let overflow = regs.get_crt_data(CRTReg::Overflow) as u32;
+    let vr_start = regs.get_crt_data(CRTReg::VerticalRetraceStart) as u32
+        | (overflow & 0x04) << 6
+        | (overflow & 0x80) << 2;
+    let vr_end = regs.get_crt_data(CRTReg::VerticalRetraceEnd) as u32 & 0x0F;
+    let vr_len = match vr_end.wrapping_sub(vr_start) & 0x0F {
+        0 => 16,
+        len => len,
+    };
+    (vr_start, vr_start + vr_len)
+}
+
+/// The blanking ends at the first counter value after the start whose
+/// lower bits (mask) match the end register
+fn blanking_end(start: u32, end_bits: u32, mask: u32) -> u32 {
+    let end = (start & !mask) | end_bits;
+    if end <= start { end + mask + 1 } else { end }
+}
+
+/// Constructs the Line Compare from the register + overflow bit 8 and maximum scan line bit 9
+pub fn get_line_compare_regs(regs: &VGARegs) -> u32 {
+    let lc_lower = regs.get_crt_data(CRTReg::LineCompare) as u32;
+    let lc_bit_8 = ((regs.get_crt_data(CRTReg::Overflow) & 0b0001_0000) >> 4) as u32;
+    let lc_bit_9 = ((regs.get_crt_data(CRTReg::MaximumScanLine) & 0b0100_0000) >> 6) as u32;
+    lc_lower | (lc_bit_8 << 8) | (lc_bit_9 << 9)
+}
+
+/// Offsets of the character map A and B in plane 2 as selected
+/// by SCReg::CharacterMapSelect
+pub fn get_character_maps_regs(regs: &VGARegs) -> (usize, usize) {
+    let v = regs.get_sc_data(SCReg::CharacterMapSelect);
+    let map_a = ((v >> 2) & 0x03) | ((v >> 3) & 0x04);
+    let map_b = (v & 0x03) | ((v >> 2) & 0x04);
+    (character_map_offset(map_a), character_map_offset(map_b))
+}
+
+/// Physical start address and size of the host memory window as selected
+/// by GCReg::MiscGraphics (bits 2-3)
+pub fn get_memory_map_regs(regs: &VGARegs) -> (usize, usize) {
+    match (regs.get_gc_data(GCReg::MiscGraphics) >> 2) & 0x03 {
+        0x00 => (0xA0000, 0x20000),
+        0x01 => (0xA0000, 0x10000),
+        0x02 => (0xB0000, 0x8000),
+        _ => (0xB8000, 0x8000),
+    }
+}
+
+/// Offset of the character map `map` (0-7) in plane 2
+pub fn character_map_offset(map: u8) -> usize {
+    (map & 0x03) as usize * 0x4000 + if map & 0x04 != 0 { 0x2000 } else { 0 }
+}
+
 /// display enable NOT
+#[deprecated(note = "GeneralReg::InputStatus1 follows the virtual raster clock")]
 pub fn set_de(vga: &mut VGAEmu, display_mode: bool) {
     let v0 = vga.regs.get_general_reg(GeneralReg::InputStatus1);
     if display_mode {
@@ -59,6 +293,7 @@ pub fn set_de(vga: &mut VGAEmu, display_mode: bool) {
 }
 
 /// vertical retrace
+#[deprecated(note = "GeneralReg::InputStatus1 follows the virtual raster clock")]
 pub fn set_vr(vga: &mut VGAEmu, set: bool) {
     let v0 = vga.regs.get_general_reg(GeneralReg::InputStatus1);
     if set {
@@ -82,24 +317,23 @@ pub fn fill_pattern_x(
 
     for i in 0..4 {
         vga.vga_emu.regs.set_sc_data(SCReg::MapMask, 1);
-        vga.vga_emu
-            .write_mem((PATTERN_BUFFER - 1) + i, pattern[i * 4]);
+        vga.vga_emu.write_mem(PATTERN_BUFFER + i, pattern[i * 4]);
 
         vga.vga_emu.regs.set_sc_data(SCReg::MapMask, 2);
         vga.vga_emu
-            .write_mem((PATTERN_BUFFER - 1) + i, pattern[i * 4 + 1]);
+            .write_mem(PATTERN_BUFFER + i, pattern[i * 4 + 1]);
 
         vga.vga_emu.regs.set_sc_data(SCReg::MapMask, 4);
         vga.vga_emu
-            .write_mem((PATTERN_BUFFER - 1) + i, pattern[i * 4 + 2]);
+            .write_mem(PATTERN_BUFFER + i, pattern[i * 4 + 2]);
 
         vga.vga_emu.regs.set_sc_data(SCReg::MapMask, 8);
         vga.vga_emu
-            .write_mem((PATTERN_BUFFER - 1) + i, pattern[i * 4 + 3]);
+            .write_mem(PATTERN_BUFFER + i, pattern[i * 4 + 3]);
     }
     vga.vga_emu.regs.set_gc_data(GCReg::BitMask, 0);
 
-    let mut si = (start_y & 0x03) + (PATTERN_BUFFER - 1);
+    let mut si = (start_y & 0x03) + PATTERN_BUFFER;
     let mut di = start_y * SCREEN_WIDTH + (start_x >> 2) + page_base;
 
     let mut left_clip = LEFT_CLIP_PLANE_MASK[start_x & 0x03];